@@ -0,0 +1,145 @@
+use crate::{
+    errors::LockupFactoryError, events::LockupFactoryEvents, storage,
+    token_lockup::Client as TokenLockupClient, types::LockupConfig,
+};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec,
+};
+
+#[contract]
+pub struct LockupFactory;
+
+#[contractimpl]
+impl LockupFactory {
+    /********** Constructor **********/
+
+    /// (Only deployer) Initialize the factory
+    ///
+    /// ### Arguments
+    /// * `deployer` - The address that deployed the factory contract
+    /// * `salt` - The salt the factory contract was deployed with
+    /// * `lockup_wasm_hash` - The wasm hash of the lockup contract to deploy
+    ///
+    /// ### Errors
+    /// * AlreadyInitializedError - The contract has already been initialized
+    /// * UnauthorizedError - The caller is not the deployer of the factory
+    pub fn initialize(e: Env, deployer: Address, salt: BytesN<32>, lockup_wasm_hash: BytesN<32>) {
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, LockupFactoryError::AlreadyInitializedError);
+        }
+        require_deployer(&e, &deployer, &salt);
+        storage::extend_instance(&e);
+
+        storage::set_lockup_wasm_hash(&e, &lockup_wasm_hash);
+    }
+
+    /********** Read-Only **********/
+
+    /// Get the wasm hash of the lockup contract deployed by the factory
+    pub fn lockup_wasm_hash(e: Env) -> BytesN<32> {
+        storage::get_lockup_wasm_hash(&e)
+    }
+
+    /// Get the address a lockup deployed by `from` with the given salt will have
+    ///
+    /// ### Arguments
+    /// * `from` - The address deploying the lockup
+    /// * `salt` - The salt used to deploy the lockup
+    pub fn lockup_address(e: Env, from: Address, salt: BytesN<32>) -> Address {
+        let lockup_salt = get_lockup_salt(&e, &from, &salt);
+        e.deployer()
+            .with_current_contract(lockup_salt)
+            .deployed_address()
+    }
+
+    /********** Write **********/
+
+    /// (Only from) Deploy, initialize, and fund a lockup in a single invocation
+    ///
+    /// ### Arguments
+    /// * `from` - The address funding the lockup
+    /// * `config` - The configuration of the lockup
+    ///
+    /// ### Returns
+    /// The address of the deployed lockup
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not `from`
+    pub fn deploy(e: Env, from: Address, config: LockupConfig) -> Address {
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        deploy_lockup(&e, &from, config)
+    }
+
+    /// (Only from) Deploy, initialize, and fund multiple lockups in a single invocation
+    ///
+    /// ### Arguments
+    /// * `from` - The address funding the lockups
+    /// * `configs` - The configurations of the lockups
+    ///
+    /// ### Returns
+    /// The addresses of the deployed lockups, in the same order as `configs`
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not `from`
+    pub fn batch_deploy(e: Env, from: Address, configs: Vec<LockupConfig>) -> Vec<Address> {
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        let mut lockups = Vec::new(&e);
+        for config in configs.iter() {
+            lockups.push_back(deploy_lockup(&e, &from, config));
+        }
+        lockups
+    }
+}
+
+/// Require that the factory was deployed by the deployer with the salt, and that the deployer
+/// authorized the call
+///
+/// Panics if the factory's address was not derived from the deployer and salt
+fn require_deployer(e: &Env, deployer: &Address, salt: &BytesN<32>) {
+    deployer.require_auth();
+    let deployed_address = e
+        .deployer()
+        .with_address(deployer.clone(), salt.clone())
+        .deployed_address();
+    if deployed_address != e.current_contract_address() {
+        panic_with_error!(e, LockupFactoryError::UnauthorizedError);
+    }
+}
+
+/// Get the salt a lockup is deployed with, which is the hash of the address deploying the lockup
+/// and the salt it provided. This prevents other addresses from deploying to the same address.
+fn get_lockup_salt(e: &Env, from: &Address, salt: &BytesN<32>) -> BytesN<32> {
+    let mut data: Bytes = from.clone().to_xdr(e);
+    data.append(&Bytes::from(salt.clone()));
+    e.crypto().sha256(&data)
+}
+
+/// Deploy a lockup, initialize it, and deposit the initial funding from `from`
+fn deploy_lockup(e: &Env, from: &Address, config: LockupConfig) -> Address {
+    let lockup_salt = get_lockup_salt(e, from, &config.salt);
+    let lockup = e
+        .deployer()
+        .with_current_contract(lockup_salt.clone())
+        .deploy(storage::get_lockup_wasm_hash(e));
+
+    let lockup_client = TokenLockupClient::new(e, &lockup);
+    lockup_client.initialize(
+        &e.current_contract_address(),
+        &lockup_salt,
+        &config.admin,
+        &config.owner,
+        &config.unlocks,
+        &config.token_unlocks,
+        &config.beneficiaries,
+    );
+    for (token, amount) in config.deposits.iter() {
+        lockup_client.deposit(from, &token, &amount);
+    }
+
+    LockupFactoryEvents::deploy(e, lockup.clone(), config.admin, config.owner);
+    lockup
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::contracterror;
+
+/// The error codes for the contract.
+#[contracterror]
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum LockupFactoryError {
+    // Default errors to align with built-in contract
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+}
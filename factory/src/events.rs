@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct LockupFactoryEvents {}
+
+impl LockupFactoryEvents {
+    /// Emitted when a lockup is deployed by the factory
+    ///
+    /// - topics - `["deploy", lockup: Address]`
+    /// - data - `[admin: Address, owner: Address]`
+    ///
+    /// ### Arguments
+    /// * `lockup` - The address of the deployed lockup
+    /// * `admin` - The admin of the lockup
+    /// * `owner` - The owner of the lockup
+    pub fn deploy(e: &Env, lockup: Address, admin: Address, owner: Address) {
+        let topics = (Symbol::new(e, "deploy"), lockup);
+        e.events().publish(topics, (admin, owner));
+    }
+}
@@ -0,0 +1,13 @@
+#![no_std]
+
+pub mod contract;
+mod errors;
+mod events;
+mod storage;
+mod token_lockup;
+mod types;
+
+#[cfg(test)]
+extern crate std;
+#[cfg(test)]
+mod tests;
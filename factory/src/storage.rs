@@ -0,0 +1,45 @@
+use soroban_sdk::{BytesN, Env, Symbol};
+
+/********** Ledger Thresholds **********/
+
+const ONE_DAY_LEDGERS: u32 = 17280; // assumes 5 seconds per ledger
+
+const LEDGER_BUMP: u32 = 120 * ONE_DAY_LEDGERS;
+const LEDGER_THRESHOLD: u32 = LEDGER_BUMP - 20 * ONE_DAY_LEDGERS;
+
+/********** Ledger Keys **********/
+
+const LOCKUP_WASM_HASH_KEY: &str = "LockupWasm";
+
+/********** Ledger Thresholds **********/
+
+/// Bump the instance lifetime by the defined amount
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/********** Instance **********/
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .has(&Symbol::new(e, LOCKUP_WASM_HASH_KEY))
+}
+
+/// Get the wasm hash of the lockup contract
+pub fn get_lockup_wasm_hash(e: &Env) -> BytesN<32> {
+    e.storage()
+        .instance()
+        .get::<Symbol, BytesN<32>>(&Symbol::new(e, LOCKUP_WASM_HASH_KEY))
+        .unwrap()
+}
+
+/// Set the wasm hash of the lockup contract
+pub fn set_lockup_wasm_hash(e: &Env, wasm_hash: &BytesN<32>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, BytesN<32>>(&Symbol::new(e, LOCKUP_WASM_HASH_KEY), wasm_hash);
+}
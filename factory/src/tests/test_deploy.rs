@@ -0,0 +1,279 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, BytesN as _, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, BytesN, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    contract::{LockupFactory, LockupFactoryClient},
+    token_lockup::{self, Client as TokenLockupClient, Schedule, Unlock},
+    types::LockupConfig,
+};
+
+/// Register an uninitialized factory at the address derived from the deployer and a random salt
+fn register_factory<'a>(e: &Env, deployer: &Address) -> (BytesN<32>, LockupFactoryClient<'a>) {
+    let salt = BytesN::<32>::random(e);
+    let factory_id = e
+        .deployer()
+        .with_address(deployer.clone(), salt.clone())
+        .deployed_address();
+    e.register_contract(&factory_id, LockupFactory {});
+    (salt, LockupFactoryClient::new(e, &factory_id))
+}
+
+fn create_factory<'a>(e: &Env, deployer: &Address) -> LockupFactoryClient<'a> {
+    let lockup_wasm_hash = e.deployer().upload_contract_wasm(token_lockup::WASM);
+    let (salt, factory_client) = register_factory(e, deployer);
+    factory_client.initialize(deployer, &salt, &lockup_wasm_hash);
+    factory_client
+}
+
+#[test]
+fn test_factory_deploy() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let factory_client = create_factory(&e, &bombadil);
+    let result = factory_client.try_initialize(
+        &bombadil,
+        &BytesN::from_array(&e, &[0; 32]),
+        &BytesN::from_array(&e, &[0; 32]),
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(3))));
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&bombadil, &token_1_total);
+
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let expected_address = factory_client.lockup_address(&bombadil, &salt);
+    let lockup_id = factory_client.deploy(
+        &bombadil,
+        &LockupConfig {
+            salt,
+            admin: bombadil.clone(),
+            owner: frodo.clone(),
+            unlocks: unlocks.clone(),
+            token_unlocks: map![&e],
+            beneficiaries: map![&e],
+            deposits: map![&e, (token_1_id.clone(), token_1_total)],
+        },
+    );
+    assert_eq!(lockup_id, expected_address);
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                factory_client.address.clone(),
+                (Symbol::new(&e, "deploy"), lockup_id.clone()).into_val(&e),
+                (bombadil.clone(), frodo.clone()).into_val(&e)
+            )
+        ]
+    );
+
+    // validate the lockup is initialized and funded
+    let lockup_client = TokenLockupClient::new(&e, &lockup_id);
+    assert_eq!(lockup_client.admin(), Some(bombadil.clone()));
+    assert_eq!(lockup_client.owner(), frodo);
+    assert_eq!(lockup_client.unlocks(), unlocks);
+    assert_eq!(lockup_client.deposited(&token_1_id), token_1_total);
+    assert_eq!(token_1_client.balance(&lockup_id), token_1_total);
+    assert_eq!(token_1_client.balance(&bombadil), 0);
+}
+
+#[test]
+fn test_factory_batch_deploy() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let factory_client = create_factory(&e, &bombadil);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&bombadil, &(2 * token_1_total));
+
+    let frodo_salt = BytesN::from_array(&e, &[1; 32]);
+    let samwise_salt = BytesN::from_array(&e, &[2; 32]);
+    let lockups = factory_client.batch_deploy(
+        &bombadil,
+        &vec![
+            &e,
+            LockupConfig {
+                salt: frodo_salt.clone(),
+                admin: bombadil.clone(),
+                owner: frodo.clone(),
+                unlocks: unlocks.clone(),
+                token_unlocks: map![&e],
+                beneficiaries: map![&e],
+                deposits: map![&e, (token_1_id.clone(), token_1_total)],
+            },
+            LockupConfig {
+                salt: samwise_salt.clone(),
+                admin: bombadil.clone(),
+                owner: samwise.clone(),
+                unlocks: unlocks.clone(),
+                token_unlocks: map![&e],
+                beneficiaries: map![&e],
+                deposits: map![&e, (token_1_id.clone(), token_1_total)],
+            },
+        ],
+    );
+    assert_eq!(
+        lockups,
+        vec![
+            &e,
+            factory_client.lockup_address(&bombadil, &frodo_salt),
+            factory_client.lockup_address(&bombadil, &samwise_salt)
+        ]
+    );
+    assert_eq!(
+        TokenLockupClient::new(&e, &lockups.get_unchecked(0)).owner(),
+        frodo
+    );
+    assert_eq!(
+        TokenLockupClient::new(&e, &lockups.get_unchecked(1)).owner(),
+        samwise
+    );
+    assert_eq!(
+        token_1_client.balance(&lockups.get_unchecked(0)),
+        token_1_total
+    );
+    assert_eq!(
+        token_1_client.balance(&lockups.get_unchecked(1)),
+        token_1_total
+    );
+}
+
+#[test]
+fn test_factory_initialize_requires_deployer() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let gollum = Address::generate(&e);
+    let lockup_wasm_hash = e.deployer().upload_contract_wasm(token_lockup::WASM);
+
+    let (salt, factory_client) = register_factory(&e, &bombadil);
+
+    // validate another address can't initialize the factory before the deployer
+    let result = factory_client.try_initialize(&gollum, &salt, &BytesN::random(&e));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    factory_client.initialize(&bombadil, &salt, &lockup_wasm_hash);
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    factory_client.address.clone(),
+                    Symbol::new(&e, "initialize"),
+                    vec![
+                        &e,
+                        bombadil.into_val(&e),
+                        salt.into_val(&e),
+                        lockup_wasm_hash.into_val(&e)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(factory_client.lockup_wasm_hash(), lockup_wasm_hash);
+}
+
+#[test]
+fn test_factory_deploy_front_run() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let gollum = Address::generate(&e);
+
+    let factory_client = create_factory(&e, &bombadil);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let expected_address = factory_client.lockup_address(&bombadil, &salt);
+    assert_ne!(
+        factory_client.lockup_address(&gollum, &salt),
+        expected_address
+    );
+
+    // another address deploys with the same salt first
+    let gollum_lockup_id = factory_client.deploy(
+        &gollum,
+        &LockupConfig {
+            salt: salt.clone(),
+            admin: gollum.clone(),
+            owner: gollum.clone(),
+            unlocks: unlocks.clone(),
+            token_unlocks: map![&e],
+            beneficiaries: map![&e],
+            deposits: map![&e],
+        },
+    );
+    assert_ne!(gollum_lockup_id, expected_address);
+
+    // validate the original deployer still deploys to the expected address
+    let lockup_id = factory_client.deploy(
+        &bombadil,
+        &LockupConfig {
+            salt,
+            admin: bombadil.clone(),
+            owner: frodo.clone(),
+            unlocks,
+            token_unlocks: map![&e],
+            beneficiaries: map![&e],
+            deposits: map![&e],
+        },
+    );
+    assert_eq!(lockup_id, expected_address);
+    let lockup_client = TokenLockupClient::new(&e, &lockup_id);
+    assert_eq!(lockup_client.admin(), Some(bombadil));
+    assert_eq!(lockup_client.owner(), frodo);
+}
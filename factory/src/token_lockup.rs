@@ -0,0 +1,3 @@
+#![allow(clippy::too_many_arguments)]
+
+soroban_sdk::contractimport!(file = "../target/wasm32-unknown-unknown/optimized/token_lockup.wasm");
@@ -0,0 +1,22 @@
+use soroban_sdk::{contracttype, Address, BytesN, Map};
+
+use crate::token_lockup::Schedule;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LockupConfig {
+    /// The salt used to derive the lockup's address, along with the address deploying the lockup
+    pub salt: BytesN<32>,
+    /// The admin of the lockup
+    pub admin: Address,
+    /// The owner of the lockup
+    pub owner: Address,
+    /// The unlock schedule of the lockup
+    pub unlocks: Schedule,
+    /// The unlock schedules of tokens that do not use `unlocks`, keyed by token address
+    pub token_unlocks: Map<Address, Schedule>,
+    /// The shares (in bps) of each beneficiary, or empty if the owner claims all tokens
+    pub beneficiaries: Map<Address, u32>,
+    /// The amount of each token to deposit into the lockup, keyed by token address
+    pub deposits: Map<Address, i128>,
+}
@@ -0,0 +1,671 @@
+use soroban_sdk::{
+    token::TokenClient, unwrap::UnwrapOptimized, Address, Env, Map, String, Vec, I256,
+};
+
+use crate::{
+    events::LockupEvents,
+    storage::{self, ClaimState},
+    types::{
+        AmountUnlock, CliffLinear, LeaverPolicy, PendingRevoke, Periodic, Schedule, TopUpPolicy,
+        Unlock,
+    },
+    validation::require_not_position_token,
+};
+
+/// Claim the unlocked tokens and transfer them to the recipient
+///
+/// ### Arguments
+/// * `tokens` - A vector of tokens to claim
+/// * `to` - The address to transfer the claimed tokens to
+/// * `memo` - An optional memo to emit alongside each claim
+pub fn claim_tokens(e: &Env, tokens: &Vec<Address>, to: &Address, memo: &Option<String>) {
+    for token in tokens.iter() {
+        let schedule = get_token_schedule(e, &token);
+        claim_token(e, &schedule, &token, to, None);
+        if let Some(memo) = memo {
+            LockupEvents::claim_memo(e, token, to.clone(), memo.clone());
+        }
+    }
+}
+
+/// Claim the unlocked tokens of every token, except position tokens, and transfer them to the
+/// recipient. A token that can't report its balance or transfer the claim is skipped, so it does
+/// not block claims of the others.
+///
+/// ### Arguments
+/// * `tokens` - A vector of tokens to claim
+/// * `to` - The address to transfer the claimed tokens to
+pub fn claim_all_tokens(e: &Env, tokens: &Vec<Address>, to: &Address) {
+    for token in tokens.iter() {
+        if storage::get_position_token(e, &token) {
+            continue;
+        }
+        let schedule = get_token_schedule(e, &token);
+        try_claim_token(e, &schedule, &token, to);
+    }
+}
+
+/// Claim each beneficiary's share of the unlocked tokens and transfer it to the beneficiary
+///
+/// ### Arguments
+/// * `tokens` - A vector of tokens to claim
+/// * `beneficiaries` - The beneficiaries of the lockup and their shares (in bps)
+pub fn claim_share_tokens(e: &Env, tokens: &Vec<Address>, beneficiaries: &Map<Address, u32>) {
+    for token in tokens.iter() {
+        let schedule = get_token_schedule(e, &token);
+        for (beneficiary, share) in beneficiaries.iter() {
+            claim_share_token(e, &schedule, &token, &beneficiary, share);
+        }
+    }
+}
+
+/// Get the unlock schedule of a token. Uses the lockup's schedule if the token does not
+/// have its own schedule.
+pub fn get_token_schedule(e: &Env, token: &Address) -> Schedule {
+    match storage::get_token_unlocks(e, token) {
+        Some(schedule) => schedule,
+        None => storage::get_unlocks(e).unwrap_optimized(),
+    }
+}
+
+/// Check if a token has been claimed by the owner or any beneficiary
+pub fn is_token_claimed(e: &Env, token: &Address) -> bool {
+    if storage::get_claim_state(e, token).last_claim > 0 {
+        return true;
+    }
+    storage::get_beneficiaries(e)
+        .keys()
+        .iter()
+        .any(|beneficiary| storage::get_beneficiary_claim(e, &beneficiary, token).last_claim > 0)
+}
+
+/// Claim the unlocked tokens of a single token and transfer them to the recipient. Any unlocked
+/// tokens that are not claimed, or are deployed to other contracts, remain claimable.
+///
+/// Returns the amount of tokens claimed
+///
+/// ### Arguments
+/// * `schedule` - The unlock schedule of the lockup
+/// * `token` - The token to claim
+/// * `to` - The address to transfer the claimed tokens to
+/// * `amount` - The maximum amount to claim, or None to claim all unlocked tokens
+pub fn claim_token(
+    e: &Env,
+    schedule: &Schedule,
+    token: &Address,
+    to: &Address,
+    amount: Option<i128>,
+) -> i128 {
+    require_not_position_token(e, token);
+    settle_revoke(e, token);
+    let token_client = TokenClient::new(e, token);
+    let balance = token_client.balance(&e.current_contract_address());
+    let total_balance = get_total_balance(e, token, balance);
+    let amount = amount.unwrap_or(balance).min(balance);
+    let mut state = storage::get_claim_state(e, token);
+    let claim_amount =
+        update_claim_state(e, schedule, &mut state, total_balance, 10000, Some(amount));
+
+    storage::set_claim_state(e, token, &state);
+    storage::add_token(e, token);
+    token_client.transfer(&e.current_contract_address(), to, &claim_amount);
+
+    LockupEvents::claim(e, token.clone(), to.clone(), claim_amount);
+    claim_amount
+}
+
+/// Claim the unlocked tokens of a single token and transfer them to the recipient. If the token
+/// can't report its balance or transfer the claim, nothing is claimed.
+///
+/// Returns the amount of tokens claimed
+///
+/// ### Arguments
+/// * `schedule` - The unlock schedule of the lockup
+/// * `token` - The token to claim
+/// * `to` - The address to transfer the claimed tokens to
+fn try_claim_token(e: &Env, schedule: &Schedule, token: &Address, to: &Address) -> i128 {
+    let token_client = TokenClient::new(e, token);
+    if !matches!(
+        token_client.try_balance(&e.current_contract_address()),
+        Ok(Ok(_))
+    ) {
+        return 0;
+    }
+    settle_revoke(e, token);
+    let balance = token_client.balance(&e.current_contract_address());
+    let total_balance = get_total_balance(e, token, balance);
+    let mut state = storage::get_claim_state(e, token);
+    let claim_amount =
+        update_claim_state(e, schedule, &mut state, total_balance, 10000, Some(balance));
+
+    let result = token_client.try_transfer(&e.current_contract_address(), to, &claim_amount);
+    if !matches!(result, Ok(Ok(()))) {
+        return 0;
+    }
+    storage::set_claim_state(e, token, &state);
+
+    LockupEvents::claim(e, token.clone(), to.clone(), claim_amount);
+    claim_amount
+}
+
+/// Claim a beneficiary's share of the unlocked tokens of a single token and transfer them to
+/// the beneficiary. If nothing is unlocked, or the beneficiary can't receive the token, nothing
+/// is claimed, and the beneficiary's claim state is unchanged.
+///
+/// Returns the amount of tokens claimed
+///
+/// ### Arguments
+/// * `schedule` - The unlock schedule of the lockup
+/// * `token` - The token to claim
+/// * `beneficiary` - The beneficiary claiming the tokens
+/// * `share` - The beneficiary's share of the lockup (in bps)
+fn claim_share_token(
+    e: &Env,
+    schedule: &Schedule,
+    token: &Address,
+    beneficiary: &Address,
+    share: u32,
+) -> i128 {
+    require_not_position_token(e, token);
+    let token_client = TokenClient::new(e, token);
+    let balance = token_client.balance(&e.current_contract_address());
+    let total_balance = balance + storage::get_deployed(e, token);
+    let mut state = storage::get_beneficiary_claim(e, beneficiary, token);
+    let share_balance = get_share_balance(e, token, &state, total_balance, share);
+    let claim_amount =
+        update_claim_state(e, schedule, &mut state, share_balance, share, Some(balance));
+    // anyone can claim for beneficiaries, so an empty claim must not move the last claim, which
+    // would change how later deposits vest
+    if claim_amount == 0 {
+        return 0;
+    }
+
+    // a beneficiary that can't receive the token does not block claims for the others
+    let result =
+        token_client.try_transfer(&e.current_contract_address(), beneficiary, &claim_amount);
+    if !matches!(result, Ok(Ok(()))) {
+        return 0;
+    }
+    storage::set_beneficiary_claim(e, beneficiary, token, &state);
+    storage::set_claimed(e, token, &(storage::get_claimed(e, token) + claim_amount));
+
+    LockupEvents::claim(e, token.clone(), beneficiary.clone(), claim_amount);
+    claim_amount
+}
+
+/// Record the unlocked amount of a token as unclaimed before a deposit, so the deposit vests from
+/// the time of the deposit according to the top-up policy. Only applies to tokens that have been
+/// claimed, since tokens deposited before the first claim vest on the full schedule.
+///
+/// ### Arguments
+/// * `token` - The token being deposited
+pub fn checkpoint_token(e: &Env, token: &Address) {
+    if storage::get_position_token(e, token) {
+        return;
+    }
+    let schedule = get_token_schedule(e, token);
+    let balance = TokenClient::new(e, token).balance(&e.current_contract_address());
+    let total_balance = balance + storage::get_deployed(e, token);
+    let beneficiaries = storage::get_beneficiaries(e);
+    if beneficiaries.is_empty() {
+        let mut state = storage::get_claim_state(e, token);
+        if state.last_claim > 0 {
+            update_claim_state(e, &schedule, &mut state, total_balance, 10000, Some(0));
+            storage::set_claim_state(e, token, &state);
+        }
+    } else {
+        for (beneficiary, share) in beneficiaries.iter() {
+            let mut state = storage::get_beneficiary_claim(e, &beneficiary, token);
+            if state.last_claim > 0 {
+                let share_balance = get_share_balance(e, token, &state, total_balance, share);
+                update_claim_state(e, &schedule, &mut state, share_balance, share, Some(0));
+                storage::set_beneficiary_claim(e, &beneficiary, token, &state);
+            }
+        }
+    }
+}
+
+/// Revoke the unvested tokens of a single token and transfer them to the recipient. The vested
+/// tokens remain claimable by the owner, unless they are forfeited by the leaver policy. If the
+/// lockup's balance does not cover the revoked amount because tokens are deployed, the remainder
+/// is transferred once the deployed tokens are returned.
+///
+/// Returns the amount of tokens revoked
+///
+/// ### Arguments
+/// * `schedule` - The unlock schedule of the lockup
+/// * `token` - The token to revoke
+/// * `to` - The address to transfer the revoked tokens to
+/// * `policy` - The leaver policy to apply to the vested tokens
+pub fn revoke_token(
+    e: &Env,
+    schedule: &Schedule,
+    token: &Address,
+    to: &Address,
+    policy: LeaverPolicy,
+) -> i128 {
+    require_not_position_token(e, token);
+    settle_revoke(e, token);
+    let token_client = TokenClient::new(e, token);
+    let balance = token_client.balance(&e.current_contract_address());
+    let total_balance = get_total_balance(e, token, balance);
+    let mut state = storage::get_claim_state(e, token);
+    let vested_amount = match policy {
+        LeaverPolicy::Good => {
+            get_unlocked_amount(e, schedule, &state, total_balance, 10000).min(total_balance)
+        }
+        LeaverPolicy::Bad => 0,
+    };
+    let revoke_amount = total_balance - vested_amount;
+
+    state.last_claim = schedule.now(e);
+    state.unclaimed = vested_amount;
+    state.last_balance = vested_amount;
+    storage::set_claim_state(e, token, &state);
+
+    // any revoked tokens that are deployed are transferred once they are returned
+    let pending_amount = storage::get_pending_revoke(e, token).map_or(0, |pending| pending.amount);
+    let transfer_amount = revoke_amount.min(balance);
+    if revoke_amount > transfer_amount {
+        storage::set_pending_revoke(
+            e,
+            token,
+            &PendingRevoke {
+                to: to.clone(),
+                amount: pending_amount + revoke_amount - transfer_amount,
+            },
+        );
+    }
+    storage::set_revoked_amount(
+        e,
+        token,
+        &(storage::get_revoked_amount(e, token) + transfer_amount),
+    );
+    token_client.transfer(&e.current_contract_address(), to, &transfer_amount);
+
+    LockupEvents::revoke(e, token.clone(), to.clone(), revoke_amount, policy);
+    revoke_amount
+}
+
+/// Transfer any revoked tokens that were deployed at the time of revocation and have since been
+/// returned to the lockup
+///
+/// ### Arguments
+/// * `token` - The token to settle
+pub fn settle_revoke(e: &Env, token: &Address) {
+    let pending_revoke = match storage::get_pending_revoke(e, token) {
+        Some(pending_revoke) => pending_revoke,
+        None => return,
+    };
+    let token_client = TokenClient::new(e, token);
+    let balance = token_client.balance(&e.current_contract_address());
+    let transfer_amount = pending_revoke.amount.min(balance);
+    if transfer_amount <= 0 {
+        return;
+    }
+
+    if transfer_amount == pending_revoke.amount {
+        storage::del_pending_revoke(e, token);
+    } else {
+        storage::set_pending_revoke(
+            e,
+            token,
+            &PendingRevoke {
+                to: pending_revoke.to.clone(),
+                amount: pending_revoke.amount - transfer_amount,
+            },
+        );
+    }
+    storage::set_revoked_amount(
+        e,
+        token,
+        &(storage::get_revoked_amount(e, token) + transfer_amount),
+    );
+    token_client.transfer(
+        &e.current_contract_address(),
+        &pending_revoke.to,
+        &transfer_amount,
+    );
+}
+
+/// Get the lockup's balance of a token available to the owner, including any deployed tokens and
+/// excluding any revoked tokens waiting to be transferred
+fn get_total_balance(e: &Env, token: &Address, balance: i128) -> i128 {
+    let pending_amount = storage::get_pending_revoke(e, token).map_or(0, |pending| pending.amount);
+    (balance + storage::get_deployed(e, token) - pending_amount).max(0)
+}
+
+/// Calculate the amount of a token that can be claimed at the current ledger time, including
+/// any previously unlocked tokens that have not been claimed. If the lockup has been revoked,
+/// no tokens unlock after the revocation time. Position tokens can't be claimed.
+///
+/// ### Arguments
+/// * `schedule` - The unlock schedule of the lockup
+/// * `token` - The token being claimed
+/// * `balance` - The lockup's current balance of the token, excluding any deployed tokens
+pub fn get_claimable_amount(e: &Env, schedule: &Schedule, token: &Address, balance: i128) -> i128 {
+    if storage::get_position_token(e, token) {
+        return 0;
+    }
+    // any returned tokens are first used to settle a pending revoke
+    let pending_amount = storage::get_pending_revoke(e, token).map_or(0, |pending| pending.amount);
+    let balance = (balance - pending_amount).max(0);
+    let total_balance = balance + storage::get_deployed(e, token);
+    let state = storage::get_claim_state(e, token);
+    get_unlocked_amount(e, schedule, &state, total_balance, 10000).min(balance)
+}
+
+/// Calculate the amount of a token that a beneficiary can claim at the current ledger time
+///
+/// ### Arguments
+/// * `schedule` - The unlock schedule of the lockup
+/// * `token` - The token being claimed
+/// * `beneficiary` - The beneficiary claiming the tokens
+/// * `share` - The beneficiary's share of the lockup (in bps)
+/// * `balance` - The lockup's current balance of the token
+pub fn get_share_claimable_amount(
+    e: &Env,
+    schedule: &Schedule,
+    token: &Address,
+    beneficiary: &Address,
+    share: u32,
+    balance: i128,
+) -> i128 {
+    if storage::get_position_token(e, token) {
+        return 0;
+    }
+    let total_balance = balance + storage::get_deployed(e, token);
+    let state = storage::get_beneficiary_claim(e, beneficiary, token);
+    let share_balance = get_share_balance(e, token, &state, total_balance, share);
+    get_unlocked_amount(e, schedule, &state, share_balance, share)
+        .min(share_balance)
+        .min(balance)
+}
+
+/// Calculate a beneficiary's portion of the lockup's balance, including any deployed tokens. The
+/// share applies to all tokens the lockup has held, which is the balance plus any claimed tokens,
+/// less the tokens the beneficiary has already claimed.
+fn get_share_balance(
+    e: &Env,
+    token: &Address,
+    state: &ClaimState,
+    balance: i128,
+    share: u32,
+) -> i128 {
+    let total = balance + storage::get_claimed(e, token);
+    (mul_div_floor(e, total, share as i128, 10000) - state.claimed).clamp(0, balance)
+}
+
+/// Update the claim state for a claim of up to `amount` tokens, or all unlocked tokens if None
+///
+/// Returns the amount of tokens claimed
+fn update_claim_state(
+    e: &Env,
+    schedule: &Schedule,
+    state: &mut ClaimState,
+    balance: i128,
+    share: u32,
+    amount: Option<i128>,
+) -> i128 {
+    let unlocked_amount = get_unlocked_amount(e, schedule, state, balance, share);
+    let claimable_amount = unlocked_amount.min(balance);
+    let claim_amount = match amount {
+        Some(amount) => amount.min(claimable_amount),
+        None => claimable_amount,
+    };
+
+    state.last_claim = schedule.now(e);
+    state.unclaimed = unlocked_amount - claim_amount;
+    state.claimed += claim_amount;
+    state.last_balance = balance - claim_amount;
+    claim_amount
+}
+
+/// Calculate the amount of a token that has been unlocked at the current time of the schedule and
+/// not claimed. For amount schedules, this can exceed the balance if the lockup is underfunded.
+///
+/// ### Arguments
+/// * `schedule` - The unlock schedule of the lockup
+/// * `state` - The claim state of the token
+/// * `balance` - The balance of the token available to the claimant
+/// * `share` - The claimant's share of the lockup (in bps), which scales amount schedules
+fn get_unlocked_amount(
+    e: &Env,
+    schedule: &Schedule,
+    state: &ClaimState,
+    balance: i128,
+    share: u32,
+) -> i128 {
+    let revoked = if schedule.is_ledger() {
+        storage::get_revoked_ledger(e)
+    } else {
+        storage::get_revoked(e)
+    };
+    let now = match revoked {
+        Some(revoked) => revoked.min(schedule.now(e)),
+        None => schedule.now(e),
+    };
+    let last_asset_claim = state.last_claim;
+    if let Schedule::Amount(unlocks) = schedule {
+        let amount_unlocked = get_amount_unlocked(unlocks, last_asset_claim, now);
+        let unlocked = state.unclaimed + mul_div_floor(e, amount_unlocked, share as i128, 10000);
+        // any surplus balance can be claimed once the final unlock occurs
+        if schedule.end_time() <= now {
+            return unlocked.max(balance);
+        }
+        return unlocked;
+    }
+
+    if schedule.end_time() <= now {
+        return balance;
+    }
+
+    if let Schedule::Cumulative(unlocks) = schedule {
+        return get_cumulative_unlocked(e, unlocks, state, balance, now);
+    }
+
+    // unlock percentages only apply to tokens that have not already been unlocked
+    let unclaimed = state.unclaimed.min(balance);
+    let mut locked = balance - unclaimed;
+
+    // tokens deposited since the last claim vest on the unlocks that already occured, if enabled.
+    // Otherwise they vest on the unlocks since the last claim, or the deposit if it was later.
+    let mut retroactive = 0;
+    if last_asset_claim > 0 && storage::get_top_up_policy(e) == TopUpPolicy::Retroactive {
+        let top_up = (balance - state.last_balance).clamp(0, locked);
+        retroactive = get_percent_unlocked(e, schedule, top_up, 0, last_asset_claim);
+        locked -= retroactive;
+    }
+
+    let unlocked = get_percent_unlocked(e, schedule, locked, last_asset_claim, now);
+    unclaimed + retroactive + unlocked
+}
+
+/// Calculate the amount of tokens unlocked by a percent based schedule between `from` and `to`
+fn get_percent_unlocked(e: &Env, schedule: &Schedule, balance: i128, from: u64, to: u64) -> i128 {
+    match schedule {
+        Schedule::Step(unlocks) | Schedule::LedgerStep(unlocks) => {
+            get_step_unlocked(unlocks, balance, from, to)
+        }
+        Schedule::Linear(unlocks) | Schedule::LedgerLinear(unlocks) => {
+            get_linear_unlocked(e, unlocks, balance, from, to)
+        }
+        Schedule::CliffLinear(cliff_linear) => {
+            get_cliff_linear_unlocked(e, cliff_linear, balance, from, to)
+        }
+        Schedule::Periodic(periodic) => get_periodic_unlocked(e, periodic, balance, from, to),
+        Schedule::Amount(_) | Schedule::Cumulative(_) => 0,
+    }
+}
+
+/// Calculate the amount of tokens unlocked by an amount schedule between the last claim and now
+fn get_amount_unlocked(unlocks: &Vec<AmountUnlock>, last_claim: u64, now: u64) -> i128 {
+    let mut unlocked = 0;
+    for unlock in unlocks.iter() {
+        if unlock.time > last_claim && unlock.time <= now {
+            unlocked += unlock.amount;
+        }
+    }
+    unlocked
+}
+
+/// Calculate the amount of tokens unlocked by a cumulative schedule and not claimed. The unlock
+/// percent applies to all tokens ever deposited, which is the balance plus any claimed tokens.
+fn get_cumulative_unlocked(
+    e: &Env,
+    unlocks: &Vec<Unlock>,
+    state: &ClaimState,
+    balance: i128,
+    now: u64,
+) -> i128 {
+    // the unlocked amount is fixed once the lockup has been revoked and the token claimed
+    if let Some(revoked) = storage::get_revoked(e) {
+        if state.last_claim >= revoked {
+            return state.unclaimed;
+        }
+    }
+
+    let mut percent = 0;
+    for unlock in unlocks.iter() {
+        if unlock.time <= now {
+            percent = unlock.percent;
+        }
+    }
+    let unlocked = mul_div_floor(e, balance + state.claimed, percent as i128, 10000);
+    (unlocked - state.claimed).max(0)
+}
+
+/// Calculate the amount of tokens unlocked by a step schedule between the last claim and now
+fn get_step_unlocked(unlocks: &Vec<Unlock>, balance: i128, last_claim: u64, now: u64) -> i128 {
+    let mut balance = balance;
+    let mut unlocked = 0;
+    for unlock in unlocks.iter() {
+        if unlock.time > last_claim && unlock.time <= now {
+            let transfer_amount = (balance * unlock.percent as i128) / 10000_i128;
+            balance -= transfer_amount;
+            unlocked += transfer_amount;
+        }
+    }
+    unlocked
+}
+
+/// Calculate the amount of tokens unlocked by a linear schedule between the last claim and now
+fn get_linear_unlocked(
+    e: &Env,
+    unlocks: &Vec<Unlock>,
+    balance: i128,
+    last_claim: u64,
+    now: u64,
+) -> i128 {
+    let mut balance = balance;
+    let mut unlocked = 0;
+    let mut prev_time: Option<u64> = None;
+    for unlock in unlocks.iter() {
+        let transfer_amount = match prev_time {
+            // the first unlock occurs at its unlock time
+            None if unlock.time > last_claim && unlock.time <= now => {
+                (balance * unlock.percent as i128) / 10000_i128
+            }
+            // the percent of remaining tokens accrues from the previous unlock time. The remaining
+            // tokens are scaled back to the start of the period to account for any amount that
+            // accrued before the last claim.
+            Some(start_time) if last_claim.max(start_time) < now.min(unlock.time) => {
+                let from = last_claim.max(start_time);
+                let to = now.min(unlock.time);
+                let percent = unlock.percent as i128;
+                let numerator = percent * (to - from) as i128;
+                let denominator = 10000_i128 * (unlock.time - start_time) as i128
+                    - percent * (from - start_time) as i128;
+                mul_div_floor(e, balance, numerator, denominator)
+            }
+            _ => 0,
+        };
+        balance -= transfer_amount;
+        unlocked += transfer_amount;
+        prev_time = Some(unlock.time);
+    }
+    unlocked
+}
+
+/// Calculate the amount of tokens unlocked by a cliff linear schedule between the last claim and now
+fn get_cliff_linear_unlocked(
+    e: &Env,
+    cliff_linear: &CliffLinear,
+    balance: i128,
+    last_claim: u64,
+    now: u64,
+) -> i128 {
+    // the remaining balance is the portion of the original amount that was not unlocked by
+    // the last claim, so scale it by the portion unlocked since the last claim
+    let total = 10000_i128 * (cliff_linear.end - cliff_linear.start) as i128;
+    let unlocked_at_last_claim = get_cliff_linear_progress(cliff_linear, last_claim);
+    let unlocked_at_now = get_cliff_linear_progress(cliff_linear, now);
+    if unlocked_at_now <= unlocked_at_last_claim {
+        return 0;
+    }
+    mul_div_floor(
+        e,
+        balance,
+        unlocked_at_now - unlocked_at_last_claim,
+        total - unlocked_at_last_claim,
+    )
+}
+
+/// Calculate the progress of a cliff linear schedule at a time, scaled by 10000 * (end - start)
+fn get_cliff_linear_progress(cliff_linear: &CliffLinear, time: u64) -> i128 {
+    let duration = (cliff_linear.end - cliff_linear.start) as i128;
+    if time < cliff_linear.cliff {
+        0
+    } else if time >= cliff_linear.end {
+        10000_i128 * duration
+    } else {
+        let cliff_percent = cliff_linear.cliff_percent as i128;
+        cliff_percent * duration
+            + (10000_i128 - cliff_percent) * (time - cliff_linear.start) as i128
+    }
+}
+
+/// Calculate the amount of tokens unlocked by a periodic schedule between the last claim and now
+fn get_periodic_unlocked(
+    e: &Env,
+    periodic: &Periodic,
+    balance: i128,
+    last_claim: u64,
+    now: u64,
+) -> i128 {
+    // the remaining balance is the portion of the original amount in the periods that had not
+    // unlocked by the last claim, so scale it by the periods unlocked since the last claim
+    let unlocked_at_last_claim = get_periodic_progress(periodic, last_claim);
+    let unlocked_at_now = get_periodic_progress(periodic, now);
+    if unlocked_at_now <= unlocked_at_last_claim {
+        return 0;
+    }
+    mul_div_floor(
+        e,
+        balance,
+        (unlocked_at_now - unlocked_at_last_claim) as i128,
+        (periodic.periods - unlocked_at_last_claim) as i128,
+    )
+}
+
+/// Calculate the number of periods of a periodic schedule unlocked at a time
+fn get_periodic_progress(periodic: &Periodic, time: u64) -> u32 {
+    if time < periodic.start {
+        return 0;
+    }
+    let elapsed = ((time - periodic.start) / periodic.period).min(periodic.periods as u64) as u32;
+    if elapsed < periodic.cliff_periods {
+        0
+    } else {
+        elapsed
+    }
+}
+
+/// Calculate `x * y / denominator`, rounding down
+fn mul_div_floor(e: &Env, x: i128, y: i128, denominator: i128) -> i128 {
+    I256::from_i128(e, x)
+        .mul(&I256::from_i128(e, y))
+        .div(&I256::from_i128(e, denominator))
+        .to_i128()
+        .unwrap_optimized()
+}
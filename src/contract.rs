@@ -1,7 +1,22 @@
-use crate::{errors::TokenLockupError, storage, types::Unlock, validation::require_valid_unlocks};
+use crate::{
+    claim::{
+        checkpoint_token, claim_all_tokens, claim_share_tokens, claim_token, claim_tokens,
+        get_claimable_amount, get_share_claimable_amount, get_token_schedule, is_token_claimed,
+        revoke_token,
+    },
+    errors::TokenLockupError,
+    events::LockupEvents,
+    invoke::invoke_call,
+    storage,
+    types::{LeaverPolicy, PendingRevoke, PendingUpgrade, Schedule, TopUpPolicy},
+    validation::{
+        require_admin, require_deployer, require_lockup_schedule, require_no_beneficiaries,
+        require_not_paused, require_valid_shares, require_valid_unlocks,
+    },
+};
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, token::TokenClient, unwrap::UnwrapOptimized, Address,
-    Env, Vec,
+    contract, contractimpl, panic_with_error, token::TokenClient, unwrap::UnwrapOptimized, vec,
+    Address, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec,
 };
 
 #[contract]
@@ -11,101 +26,909 @@ pub struct TokenLockup;
 impl TokenLockup {
     /********** Constructor **********/
 
-    /// Initialize the contract
+    /// (Only deployer) Initialize the contract
     ///
     /// ### Arguments
+    /// * `deployer` - The address that deployed the lockup contract
+    /// * `salt` - The salt the lockup contract was deployed with
     /// * `admin` - The admin of the lockup contract
     /// * `owner` - The owner of the lockup contract
-    /// * `token` - The token to lock up
-    /// * `unlocks` - A vector of unlocks. Percentages represent the portion of the lockups token balance can be claimed
+    /// * `unlocks` - The unlock schedule. Percentages represent the portion of the lockups token balance can be claimed
     ///               at the given unlock time. If multiple unlocks are claimed at once, the percentages are applied in order.
+    ///               See `Schedule` for how each schedule type unlocks tokens.
+    /// * `token_unlocks` - The unlock schedules of tokens that do not use `unlocks`, keyed by token address
+    /// * `beneficiaries` - The shares (in bps) of each beneficiary, or empty if the owner claims all tokens
     ///
     /// ### Errors
     /// * AlreadyInitializedError - The contract has already been initialized
-    /// * InvalidUnlocks - The unlock times do not represent a valid unlock sequence
-    pub fn initialize(e: Env, admin: Address, owner: Address, unlocks: Vec<Unlock>) {
+    /// * UnauthorizedError - The caller is not the deployer of the lockup
+    /// * InvalidUnlocks - The unlocks are invalid, or `unlocks` is an amount schedule
+    /// * InvalidShares - The shares do not sum to 10000
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        e: Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        admin: Address,
+        owner: Address,
+        unlocks: Schedule,
+        token_unlocks: Map<Address, Schedule>,
+        beneficiaries: Map<Address, u32>,
+    ) {
         if storage::get_is_init(&e) {
             panic_with_error!(&e, TokenLockupError::AlreadyInitializedError);
         }
+        require_deployer(&e, &deployer, &salt);
         storage::extend_instance(&e);
 
-        require_valid_unlocks(&e, &unlocks);
+        require_lockup_schedule(&e, &unlocks);
+        require_valid_unlocks(&e, &unlocks, &None);
         storage::set_unlocks(&e, &unlocks);
+        for (token, schedule) in token_unlocks.iter() {
+            require_valid_unlocks(&e, &schedule, &None);
+            storage::set_token_unlocks(&e, &token, &schedule);
+        }
+        require_valid_shares(&e, &beneficiaries);
+        if !beneficiaries.is_empty() {
+            storage::set_beneficiaries(&e, &beneficiaries);
+        }
         storage::set_admin(&e, &admin);
         storage::set_owner(&e, &owner);
 
         storage::set_is_init(&e);
+
+        LockupEvents::initialize(&e, admin, owner, unlocks);
+        for (token, schedule) in token_unlocks.iter() {
+            LockupEvents::set_token_unlocks(&e, token, None, schedule);
+        }
     }
 
     /********** Read-Only **********/
 
-    /// Get unlocks for the lockup
-    pub fn unlocks(e: Env) -> Vec<Unlock> {
+    /// Get the unlock schedule for the lockup
+    pub fn unlocks(e: Env) -> Schedule {
         storage::get_unlocks(&e).unwrap_optimized()
     }
 
-    /// Get the admin address
-    pub fn admin(e: Env) -> Address {
+    /// Get the unlock schedule of a token. Returns None if the token uses the lockup's schedule.
+    ///
+    /// ### Arguments
+    /// * `token` - The token to check
+    pub fn token_unlocks(e: Env, token: Address) -> Option<Schedule> {
+        storage::get_token_unlocks(&e, &token)
+    }
+
+    /// Get the admin address. Returns None if the admin role has been renounced, and the
+    /// lockup can no longer be modified.
+    pub fn admin(e: Env) -> Option<Address> {
         storage::get_admin(&e)
     }
 
+    /// Get the pending admin address, if an admin transfer has been proposed
+    pub fn pending_admin(e: Env) -> Option<Address> {
+        storage::get_pending_admin(&e)
+    }
+
     /// Get the owner address
     pub fn owner(e: Env) -> Address {
         storage::get_owner(&e)
     }
 
+    /// Get the pending owner address, if an owner recovery has been proposed
+    pub fn pending_owner(e: Env) -> Option<Address> {
+        storage::get_pending_owner(&e)
+    }
+
+    /// Get the beneficiaries of the lockup and their shares (in bps). Returns an empty map if
+    /// the owner claims all tokens.
+    pub fn beneficiaries(e: Env) -> Map<Address, u32> {
+        storage::get_beneficiaries(&e)
+    }
+
+    /// Get the time the lockup was revoked. Returns None if the lockup has not been revoked.
+    pub fn revoked(e: Env) -> Option<u64> {
+        storage::get_revoked(&e)
+    }
+
+    /// Check if claims are paused
+    pub fn paused(e: Env) -> bool {
+        storage::get_paused(&e)
+    }
+
+    /// Get the delay (in seconds) between proposing and executing an upgrade
+    pub fn upgrade_delay(e: Env) -> u64 {
+        storage::get_upgrade_delay(&e)
+    }
+
+    /// Get the pending upgrade, if an upgrade has been proposed. Owners can use this to
+    /// exit the lockup or object before the upgrade is executed.
+    pub fn pending_upgrade(e: Env) -> Option<PendingUpgrade> {
+        storage::get_pending_upgrade(&e)
+    }
+
+    /// Get the top-up policy, which defines how tokens deposited after the first claim of a token vest
+    pub fn top_up_policy(e: Env) -> TopUpPolicy {
+        storage::get_top_up_policy(&e)
+    }
+
+    /// Get the total amount of a token deposited into the lockup with `deposit`. Does not include
+    /// tokens transferred directly to the lockup.
+    ///
+    /// ### Arguments
+    /// * `token` - The token to check
+    pub fn deposited(e: Env, token: Address) -> i128 {
+        storage::get_deposited(&e, &token)
+    }
+
+    /// Get the tokens that have been deposited into or claimed from the lockup. Tokens transferred
+    /// directly to the lockup are included once they have been claimed. At most 20 tokens are
+    /// registered.
+    pub fn tokens(e: Env) -> Vec<Address> {
+        storage::get_tokens(&e)
+    }
+
+    /// Get the total amount of a token claimed from the lockup
+    ///
+    /// ### Arguments
+    /// * `token` - The token to check
+    pub fn claimed(e: Env, token: Address) -> i128 {
+        storage::get_claimed(&e, &token)
+    }
+
+    /// Get the total amount of a token revoked from the lockup. For tokens only funded with
+    /// `deposit`, the lockup holds `deposited - claimed - revoked` of the token.
+    ///
+    /// ### Arguments
+    /// * `token` - The token to check
+    pub fn revoked_amount(e: Env, token: Address) -> i128 {
+        storage::get_revoked_amount(&e, &token)
+    }
+
+    /// Get the revoked amount of a token that was deployed to other contracts at the time of
+    /// revocation. It is transferred to the revocation recipient as the tokens are returned.
+    ///
+    /// ### Arguments
+    /// * `token` - The token to check
+    pub fn pending_revoke(e: Env, token: Address) -> Option<PendingRevoke> {
+        storage::get_pending_revoke(&e, &token)
+    }
+
+    /// Get the amount of a token deployed to other contracts with allowlisted calls. Deployed
+    /// tokens continue to unlock, and can be claimed once they are returned to the lockup.
+    ///
+    /// ### Arguments
+    /// * `token` - The token to check
+    pub fn deployed(e: Env, token: Address) -> i128 {
+        storage::get_deployed(&e, &token)
+    }
+
+    /// Check if the owner can invoke a function of a contract from the lockup
+    ///
+    /// ### Arguments
+    /// * `contract` - The contract to check
+    /// * `function` - The function to check
+    pub fn allowed_call(e: Env, contract: Address, function: Symbol) -> bool {
+        storage::get_allowed_call(&e, &contract, &function)
+    }
+
+    /// Check if a token is a position token, which can't be claimed
+    ///
+    /// ### Arguments
+    /// * `token` - The token to check
+    pub fn position_token(e: Env, token: Address) -> bool {
+        storage::get_position_token(&e, &token)
+    }
+
+    /// Get the amount of each token the owner can currently claim. Uses the same calculation
+    /// as `claim`, but does not require authorization or modify the lockup.
+    ///
+    /// ### Arguments
+    /// * `tokens` - A vector of tokens to check
+    ///
+    /// ### Returns
+    /// A vector of claimable amounts, in the same order as `tokens`
+    ///
+    /// ### Errors
+    /// * BeneficiaryLockup - The lockup has beneficiaries, whose amounts are returned by `claimable_share`
+    pub fn claimable(e: Env, tokens: Vec<Address>) -> Vec<i128> {
+        require_no_beneficiaries(&e);
+        let mut claimable = Vec::new(&e);
+        for token in tokens.iter() {
+            let unlocks = get_token_schedule(&e, &token);
+            let balance = TokenClient::new(&e, &token).balance(&e.current_contract_address());
+            claimable.push_back(get_claimable_amount(&e, &unlocks, &token, balance));
+        }
+        claimable
+    }
+
+    /// Get the amount of each token a beneficiary can currently claim. Uses the same calculation
+    /// as `claim`, but does not modify the lockup.
+    ///
+    /// ### Arguments
+    /// * `beneficiary` - The beneficiary to check
+    /// * `tokens` - A vector of tokens to check
+    ///
+    /// ### Returns
+    /// A vector of claimable amounts, in the same order as `tokens`
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The address is not a beneficiary
+    pub fn claimable_share(e: Env, beneficiary: Address, tokens: Vec<Address>) -> Vec<i128> {
+        let share = match storage::get_beneficiaries(&e).get(beneficiary.clone()) {
+            Some(share) => share,
+            None => panic_with_error!(&e, TokenLockupError::UnauthorizedError),
+        };
+        let mut claimable = Vec::new(&e);
+        for token in tokens.iter() {
+            let unlocks = get_token_schedule(&e, &token);
+            let balance = TokenClient::new(&e, &token).balance(&e.current_contract_address());
+            claimable.push_back(get_share_claimable_amount(
+                &e,
+                &unlocks,
+                &token,
+                &beneficiary,
+                share,
+                balance,
+            ));
+        }
+        claimable
+    }
+
     /********** Write **********/
 
-    /// (Only admin) Set new unlocks for the lockup. The new unlocks must retain
-    /// any existing unlocks that have already passed their unlock time.
+    /// (Only admin) Set new unlocks for the lockup, or for a single token. The new unlocks must retain
+    /// any existing unlocks that have already passed their unlock time. For linear schedules,
+    /// the unlock currently accruing must also be retained. Cliff linear schedules can't be
+    /// changed once the cliff has passed. If a token does not have its own unlocks yet, the new
+    /// unlocks are validated against the lockup's unlocks, unless they have not started and the
+    /// token has not been claimed. Amount schedules can only be set for a token.
     ///
     /// ### Arguments
-    /// * `new_unlocks` - The new unlocks to set
+    /// * `new_unlocks` - The new unlock schedule to set
+    /// * `token` - The token to set the unlocks for, or None to set the lockup's unlocks
     ///
     /// ### Errors
     /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
     /// * InvalidUnlocks - The unlock times do not represent a valid unlock sequence
-    pub fn set_unlocks(e: Env, new_unlocks: Vec<Unlock>) {
-        storage::get_admin(&e).require_auth();
+    /// * LockupRevoked - The lockup has been revoked
+    pub fn set_unlocks(e: Env, new_unlocks: Schedule, token: Option<Address>) {
+        require_admin(&e).require_auth();
+
+        if storage::get_revoked(&e).is_some() {
+            panic_with_error!(&e, TokenLockupError::LockupRevoked);
+        }
+
+        match token {
+            Some(token) => {
+                let old_unlocks = storage::get_token_unlocks(&e, &token);
+                // a token that has not used the lockup's schedule can be given any schedule, such
+                // as a ledger schedule on a lockup with a time schedule
+                let prev_unlocks = get_token_schedule(&e, &token);
+                let prev_unlocks = match old_unlocks {
+                    None if prev_unlocks.start_time() > prev_unlocks.now(&e)
+                        && !is_token_claimed(&e, &token) =>
+                    {
+                        None
+                    }
+                    _ => Some(prev_unlocks),
+                };
+                require_valid_unlocks(&e, &new_unlocks, &prev_unlocks);
+
+                storage::set_token_unlocks(&e, &token, &new_unlocks);
 
-        require_valid_unlocks(&e, &new_unlocks);
+                LockupEvents::set_token_unlocks(&e, token, old_unlocks, new_unlocks);
+            }
+            None => {
+                require_lockup_schedule(&e, &new_unlocks);
+                let old_unlocks = storage::get_unlocks(&e).unwrap_optimized();
+                require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks.clone()));
 
-        storage::set_unlocks(&e, &new_unlocks);
+                storage::set_unlocks(&e, &new_unlocks);
+
+                LockupEvents::set_unlocks(&e, old_unlocks, new_unlocks);
+            }
+        }
+    }
+
+    /// (Only admin) Set the top-up policy, which defines how tokens deposited after the first claim
+    /// of a token vest. Applies to any tokens deposited since the last claim of each token.
+    ///
+    /// ### Arguments
+    /// * `policy` - The new top-up policy
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    pub fn set_top_up_policy(e: Env, policy: TopUpPolicy) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        storage::set_top_up_policy(&e, &policy);
+
+        LockupEvents::set_top_up_policy(&e, policy);
+    }
+
+    /// (Only admin) Allow or disallow the owner to invoke a function of a contract from the lockup,
+    /// such as supplying locked tokens to a lending pool. The owner chooses the arguments of the
+    /// call, so only allow functions that credit the resulting position to the caller. A function
+    /// that credits an arbitrary address lets the owner withdraw locked tokens.
+    ///
+    /// ### Arguments
+    /// * `contract` - The contract to invoke
+    /// * `function` - The function to invoke
+    /// * `allowed` - If the owner can invoke the function
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    pub fn set_allowed_call(e: Env, contract: Address, function: Symbol, allowed: bool) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        storage::set_allowed_call(&e, &contract, &function, &allowed);
+
+        LockupEvents::set_allowed_call(&e, contract, function, allowed);
+    }
+
+    /// (Only admin) Set if a token is a position token received from an allowlisted call. Position
+    /// tokens can't be claimed, since the tokens deployed to receive them remain subject to the
+    /// schedule. They must be redeemed with an allowlisted call before the tokens can be claimed.
+    /// Tokens included in `invoke` deployments are marked automatically if a call that deploys
+    /// tokens increases their balance.
+    ///
+    /// ### Arguments
+    /// * `token` - The token
+    /// * `is_position` - If the token is a position token
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    pub fn set_position_token(e: Env, token: Address, is_position: bool) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        storage::set_position_token(&e, &token, &is_position);
+
+        LockupEvents::set_position_token(&e, token, is_position);
+    }
+
+    /// (Only admin) Propose a new admin for the lockup. The new admin must call `accept_admin`
+    /// before the admin is changed. Replaces any existing pending admin.
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The proposed admin
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    pub fn propose_admin(e: Env, new_admin: Address) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        storage::set_pending_admin(&e, &new_admin);
+
+        LockupEvents::propose_admin(&e, new_admin);
+    }
+
+    /// (Only pending admin) Accept the admin role for the lockup
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the pending admin
+    /// * NoPendingAdmin - There is no pending admin
+    pub fn accept_admin(e: Env) {
+        let pending_admin = match storage::get_pending_admin(&e) {
+            Some(pending_admin) => pending_admin,
+            None => panic_with_error!(&e, TokenLockupError::NoPendingAdmin),
+        };
+        pending_admin.require_auth();
+        storage::extend_instance(&e);
+
+        let old_admin = storage::get_admin(&e).unwrap_optimized();
+        storage::set_admin(&e, &pending_admin);
+        storage::del_pending_admin(&e);
+
+        LockupEvents::accept_admin(&e, old_admin, pending_admin);
+    }
+
+    /// (Only admin) Cancel the pending admin proposal
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    /// * NoPendingAdmin - There is no pending admin
+    pub fn cancel_admin_proposal(e: Env) {
+        require_admin(&e).require_auth();
+
+        let pending_admin = match storage::get_pending_admin(&e) {
+            Some(pending_admin) => pending_admin,
+            None => panic_with_error!(&e, TokenLockupError::NoPendingAdmin),
+        };
+        storage::del_pending_admin(&e);
+
+        LockupEvents::cancel_admin_proposal(&e, pending_admin);
+    }
+
+    /// (Only owner and admin) Set a new owner for the lockup. Any tokens that have not
+    /// been claimed are claimable by the new owner. Clears any pending owner. If the admin
+    /// role has been renounced, only the owner is required.
+    ///
+    /// ### Arguments
+    /// * `new_owner` - The new owner
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the owner and admin
+    pub fn set_owner(e: Env, new_owner: Address) {
+        let old_owner = storage::get_owner(&e);
+        old_owner.require_auth();
+        if let Some(admin) = storage::get_admin(&e) {
+            admin.require_auth();
+        }
+        storage::extend_instance(&e);
+
+        storage::set_owner(&e, &new_owner);
+        storage::del_pending_owner(&e);
+
+        LockupEvents::set_owner(&e, old_owner, new_owner);
+    }
+
+    /// (Only admin) Propose a new owner for the lockup without the current owner's consent,
+    /// for use if the owner has lost access to their account. The new owner must call
+    /// `accept_owner` before the owner is changed. Replaces any existing pending owner.
+    ///
+    /// ### Arguments
+    /// * `new_owner` - The proposed owner
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    pub fn propose_owner(e: Env, new_owner: Address) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        storage::set_pending_owner(&e, &new_owner);
+
+        LockupEvents::propose_owner(&e, new_owner);
+    }
+
+    /// (Only pending owner) Accept ownership of the lockup
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the pending owner
+    /// * NoPendingOwner - There is no pending owner
+    pub fn accept_owner(e: Env) {
+        let pending_owner = match storage::get_pending_owner(&e) {
+            Some(pending_owner) => pending_owner,
+            None => panic_with_error!(&e, TokenLockupError::NoPendingOwner),
+        };
+        pending_owner.require_auth();
+        storage::extend_instance(&e);
+
+        let old_owner = storage::get_owner(&e);
+        storage::set_owner(&e, &pending_owner);
+        storage::del_pending_owner(&e);
+
+        LockupEvents::accept_owner(&e, old_owner, pending_owner);
+    }
+
+    /// (Only admin) Cancel the pending owner proposal
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    /// * NoPendingOwner - There is no pending owner
+    pub fn cancel_owner_proposal(e: Env) {
+        require_admin(&e).require_auth();
+
+        let pending_owner = match storage::get_pending_owner(&e) {
+            Some(pending_owner) => pending_owner,
+            None => panic_with_error!(&e, TokenLockupError::NoPendingOwner),
+        };
+        storage::del_pending_owner(&e);
+
+        LockupEvents::cancel_owner_proposal(&e, pending_owner);
+    }
+
+    /// (Only admin) Revoke the lockup, for use if the owner leaves before the tokens fully vest.
+    /// Vesting stops at the time of the first revocation, and the unvested tokens are transferred
+    /// to `to`. With a good leaver policy, the vested tokens remain claimable by the owner. With a
+    /// bad leaver policy, any vested tokens that have not been claimed are also transferred to `to`.
+    ///
+    /// Tokens not included in `tokens` stop vesting, and can be revoked with a later call. Revoked
+    /// tokens that are deployed to other contracts are transferred to `to` once they are returned,
+    /// which the admin can do with `invoke`.
+    ///
+    /// ### Arguments
+    /// * `tokens` - A vector of tokens to revoke
+    /// * `to` - The address to transfer the revoked tokens to
+    /// * `policy` - The leaver policy to apply to the vested tokens
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    /// * BeneficiaryLockup - The lockup has beneficiaries
+    pub fn revoke(e: Env, tokens: Vec<Address>, to: Address, policy: LeaverPolicy) {
+        require_admin(&e).require_auth();
+        require_no_beneficiaries(&e);
+        storage::extend_instance(&e);
+
+        if storage::get_revoked(&e).is_none() {
+            storage::set_revoked(&e, &e.ledger().timestamp());
+            storage::set_revoked_ledger(&e, &(e.ledger().sequence() as u64));
+        }
+
+        for token in tokens.iter() {
+            let unlocks = get_token_schedule(&e, &token);
+            revoke_token(&e, &unlocks, &token, &to, policy);
+        }
+    }
+
+    /// (Only admin) Pause claims, for use if a token is compromised or a dispute arises. Tokens
+    /// continue to unlock while claims are paused.
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    pub fn pause(e: Env) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        storage::set_paused(&e, &true);
+
+        LockupEvents::pause(&e);
+    }
+
+    /// (Only admin) Unpause claims
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    pub fn unpause(e: Env) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        storage::set_paused(&e, &false);
+
+        LockupEvents::unpause(&e);
+    }
+
+    /// (Only admin) Set the delay (in seconds) between proposing and executing an upgrade. The
+    /// delay can only be increased, so owners can rely on it. Any pending upgrade is delayed by the
+    /// increase.
+    ///
+    /// ### Arguments
+    /// * `delay` - The new upgrade delay
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    /// * InvalidUpgradeDelay - The delay is less than the current delay
+    pub fn set_upgrade_delay(e: Env, delay: u64) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        let prev_delay = storage::get_upgrade_delay(&e);
+        if delay < prev_delay {
+            panic_with_error!(&e, TokenLockupError::InvalidUpgradeDelay);
+        }
+        storage::set_upgrade_delay(&e, &delay);
+        if let Some(mut pending_upgrade) = storage::get_pending_upgrade(&e) {
+            pending_upgrade.executable_at += delay - prev_delay;
+            storage::set_pending_upgrade(&e, &pending_upgrade);
+        }
+
+        LockupEvents::set_upgrade_delay(&e, delay);
+    }
+
+    /// (Only admin) Propose an upgrade of the lockup to a new wasm. The upgrade can be executed
+    /// with `upgrade` once the upgrade delay has passed. Replaces any existing pending upgrade.
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the wasm to upgrade to
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    pub fn propose_upgrade(e: Env, wasm_hash: BytesN<32>) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        let executable_at = e.ledger().timestamp() + storage::get_upgrade_delay(&e);
+        storage::set_pending_upgrade(
+            &e,
+            &PendingUpgrade {
+                wasm_hash: wasm_hash.clone(),
+                executable_at,
+            },
+        );
+
+        LockupEvents::propose_upgrade(&e, wasm_hash, executable_at);
+    }
+
+    /// (Only admin) Cancel the pending upgrade
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    /// * NoPendingUpgrade - There is no pending upgrade
+    pub fn cancel_upgrade(e: Env) {
+        require_admin(&e).require_auth();
+
+        let pending_upgrade = match storage::get_pending_upgrade(&e) {
+            Some(pending_upgrade) => pending_upgrade,
+            None => panic_with_error!(&e, TokenLockupError::NoPendingUpgrade),
+        };
+        storage::del_pending_upgrade(&e);
+
+        LockupEvents::cancel_upgrade(&e, pending_upgrade.wasm_hash);
+    }
+
+    /// (Only admin) Execute the pending upgrade once the upgrade delay has passed
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has been renounced
+    /// * NoPendingUpgrade - There is no pending upgrade
+    /// * UpgradeNotReady - The upgrade delay has not passed
+    pub fn upgrade(e: Env) {
+        require_admin(&e).require_auth();
+        storage::extend_instance(&e);
+
+        let pending_upgrade = match storage::get_pending_upgrade(&e) {
+            Some(pending_upgrade) => pending_upgrade,
+            None => panic_with_error!(&e, TokenLockupError::NoPendingUpgrade),
+        };
+        if pending_upgrade.executable_at > e.ledger().timestamp() {
+            panic_with_error!(&e, TokenLockupError::UpgradeNotReady);
+        }
+        storage::del_pending_upgrade(&e);
+
+        e.deployer()
+            .update_current_contract_wasm(pending_upgrade.wasm_hash.clone());
+
+        LockupEvents::upgrade(&e, pending_upgrade.wasm_hash);
+    }
+
+    /// (Only admin) Permanently renounce the admin role. The unlocks can no longer be changed,
+    /// converting the lockup into an immutable lockup that can't be upgraded. Clears any pending
+    /// admin, owner, or upgrade, and unpauses claims.
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the admin
+    /// * AdminRenounced - The admin role has already been renounced
+    pub fn renounce_admin(e: Env) {
+        let admin = require_admin(&e);
+        admin.require_auth();
+        storage::extend_instance(&e);
+
+        storage::del_admin(&e);
+        storage::del_pending_admin(&e);
+        storage::del_pending_owner(&e);
+        storage::del_pending_upgrade(&e);
+        storage::set_paused(&e, &false);
+
+        LockupEvents::renounce_admin(&e, admin);
+    }
+
+    /// Deposit tokens into the lockup. The deposit is recorded, unlike tokens transferred directly
+    /// to the lockup. Deposits after the first claim of a token vest from the time of the deposit
+    /// according to the top-up policy. The token is registered if the deposit is from the owner or
+    /// admin, increases the lockup's balance, and the registry is not full.
+    ///
+    /// ### Arguments
+    /// * `from` - The address to transfer the tokens from
+    /// * `token` - The token to deposit
+    /// * `amount` - The amount of tokens to deposit
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not `from`
+    /// * NegativeAmountError - The amount is negative
+    pub fn deposit(e: Env, from: Address, token: Address, amount: i128) {
+        from.require_auth();
+        storage::extend_instance(&e);
+
+        if amount < 0 {
+            panic_with_error!(&e, TokenLockupError::NegativeAmountError);
+        }
+
+        checkpoint_token(&e, &token);
+        let token_client = TokenClient::new(&e, &token);
+        let prev_balance = token_client.balance(&e.current_contract_address());
+        token_client.transfer(&from, &e.current_contract_address(), &amount);
+        storage::set_deposited(&e, &token, &(storage::get_deposited(&e, &token) + amount));
+        // anyone can deposit, so only register tokens the lockup's parties actually funded
+        let balance = token_client.balance(&e.current_contract_address());
+        let is_party =
+            from == storage::get_owner(&e) || Some(from.clone()) == storage::get_admin(&e);
+        if is_party && amount > 0 && balance - prev_balance >= amount {
+            storage::add_token(&e, &token);
+        }
+
+        LockupEvents::deposit(&e, token, from, amount);
+    }
+
+    /// (Only owner) Delegate the lockup's votes for a token, so locked tokens still count in
+    /// governance. The token must implement a votes interface with
+    /// `delegate(account: Address, delegatee: Address)`.
+    ///
+    /// ### Arguments
+    /// * `token` - The votes token
+    /// * `delegatee` - The address to delegate the votes to
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the owner
+    pub fn delegate(e: Env, token: Address, delegatee: Address) {
+        storage::get_owner(&e).require_auth();
+        storage::extend_instance(&e);
+
+        let args = vec![
+            &e,
+            e.current_contract_address().into_val(&e),
+            delegatee.into_val(&e),
+        ];
+        e.invoke_contract::<()>(&token, &Symbol::new(&e, "delegate"), args);
+
+        LockupEvents::delegate(&e, token, delegatee);
+    }
+
+    /// (Only owner) Invoke an allowlisted call from the lockup, such as supplying locked tokens to a
+    /// lending pool. The contract can transfer up to the deployment amount of each token. Decreases
+    /// in the lockup's balance of these tokens are recorded as deployed, and increases as returned.
+    /// Once revoked, only the admin, or the owner if it is renounced, can invoke to return tokens.
+    ///
+    /// ### Arguments
+    /// * `contract` - The contract to invoke
+    /// * `function` - The function to invoke
+    /// * `args` - The arguments to invoke the function with
+    /// * `deployments` - The amount of each token the contract can transfer from the lockup. Tokens
+    ///                   returned or received by the call must be included with an amount of 0.
+    ///
+    /// ### Returns
+    /// The result of the call
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the owner, or the admin once revoked
+    /// * CallNotAllowed - The call has not been allowed by the admin
+    /// * NegativeAmountError - A deployment amount is negative
+    /// * LockupRevoked - A deployment amount is positive once revoked
+    pub fn invoke(
+        e: Env,
+        contract: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        deployments: Map<Address, i128>,
+    ) -> Val {
+        // once revoked, the admin returns deployed tokens, or the owner if the admin is renounced
+        match (storage::get_revoked(&e), storage::get_admin(&e)) {
+            (Some(_), Some(admin)) => admin.require_auth(),
+            _ => storage::get_owner(&e).require_auth(),
+        }
+        storage::extend_instance(&e);
+
+        let (result, deployed) = invoke_call(&e, &contract, &function, &args, &deployments);
+
+        LockupEvents::invoke(&e, contract, function, deployed);
+        result
     }
 
     /// (Only owner) Claim the unlocked tokens. The tokens are transferred to the owner.
     ///
+    /// If the lockup has beneficiaries, each beneficiary's share of the unlocked tokens is
+    /// transferred to the beneficiary instead, and no authorization is required. Each beneficiary
+    /// is entitled to their share of all tokens the lockup has held, and their portion unlocks
+    /// on the lockup's schedule independently of the other beneficiaries. Beneficiaries with
+    /// nothing unlocked are skipped, so their last claim is unchanged.
+    ///
     /// ### Arguments
     /// * `tokens` - A vector of tokens to claim
     ///
     /// ### Errors
     /// * UnauthorizedError - The caller is not the owner
     /// * NoUnlockedTokens - There are not tokens to claim for a given asset
+    /// * PositionToken - A token is a position token
+    /// * ClaimsPaused - Claims are paused
     pub fn claim(e: Env, tokens: Vec<Address>) {
+        let beneficiaries = storage::get_beneficiaries(&e);
+        if !beneficiaries.is_empty() {
+            require_not_paused(&e);
+            claim_share_tokens(&e, &tokens, &beneficiaries);
+            return;
+        }
+
         let owner = storage::get_owner(&e);
         owner.require_auth();
+        require_not_paused(&e);
 
-        let unlocks = storage::get_unlocks(&e).unwrap_optimized();
-        let is_fully_unlocked = unlocks.last_unchecked().time <= e.ledger().timestamp();
+        claim_tokens(&e, &tokens, &owner, &None);
+    }
 
-        for token in tokens.iter() {
-            let mut claim_amount = 0;
-            let token_client = TokenClient::new(&e, &token);
-            let mut balance = token_client.balance(&e.current_contract_address());
-            if is_fully_unlocked {
-                claim_amount = balance;
-            } else {
-                let last_asset_claim = storage::get_last_claim(&e, &token);
-                for unlock in unlocks.iter() {
-                    if unlock.time > last_asset_claim && unlock.time <= e.ledger().timestamp() {
-                        let transfer_amount = (balance * unlock.percent as i128) / 10000_i128;
-                        balance -= transfer_amount;
-                        claim_amount += transfer_amount;
-                    }
-                }
+    /// (Only owner) Add or remove a token from the registry returned by `tokens`, such as to
+    /// remove a token that can't be claimed from `claim_all`
+    ///
+    /// ### Arguments
+    /// * `token` - The token
+    /// * `registered` - If the token is registered
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the owner
+    /// * TooManyTokens - The registry is full
+    pub fn set_registered_token(e: Env, token: Address, registered: bool) {
+        storage::get_owner(&e).require_auth();
+        storage::extend_instance(&e);
+
+        if registered {
+            if !storage::add_token(&e, &token) {
+                panic_with_error!(&e, TokenLockupError::TooManyTokens);
             }
-            storage::set_last_claim(&e, &token, &e.ledger().timestamp());
-            token_client.transfer(&e.current_contract_address(), &owner, &claim_amount);
+        } else {
+            storage::remove_token(&e, &token);
+        }
+
+        LockupEvents::set_registered_token(&e, token, registered);
+    }
+
+    /// (Only owner) Claim the unlocked tokens of every token returned by `tokens`, except position
+    /// tokens. The tokens are transferred to the owner. Tokens whose balance can't be read, or that
+    /// can't be transferred, are skipped and remain claimable.
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the owner
+    /// * ClaimsPaused - Claims are paused
+    /// * BeneficiaryLockup - The lockup has beneficiaries, who are paid with `claim`
+    pub fn claim_all(e: Env) {
+        let owner = storage::get_owner(&e);
+        owner.require_auth();
+        require_not_paused(&e);
+        require_no_beneficiaries(&e);
+
+        claim_all_tokens(&e, &storage::get_tokens(&e), &owner);
+    }
+
+    /// (Only owner) Claim the unlocked tokens and transfer them to a recipient other than the owner.
+    ///
+    /// ### Arguments
+    /// * `tokens` - A vector of tokens to claim
+    /// * `to` - The address to transfer the claimed tokens to
+    /// * `memo` - An optional memo, emitted alongside each claim to attribute the transfer
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the owner
+    /// * ClaimsPaused - Claims are paused
+    /// * BeneficiaryLockup - The lockup has beneficiaries, who are paid with `claim`
+    pub fn claim_to(e: Env, tokens: Vec<Address>, to: Address, memo: Option<String>) {
+        storage::get_owner(&e).require_auth();
+        require_not_paused(&e);
+        require_no_beneficiaries(&e);
+
+        claim_tokens(&e, &tokens, &to, &memo);
+    }
+
+    /// (Only owner) Claim up to `amount` of the unlocked tokens of a single token. The tokens are
+    /// transferred to the owner. Any unlocked tokens that are not claimed remain claimable.
+    ///
+    /// ### Arguments
+    /// * `token` - The token to claim
+    /// * `amount` - The maximum amount of tokens to claim
+    ///
+    /// ### Returns
+    /// The amount of tokens claimed
+    ///
+    /// ### Errors
+    /// * UnauthorizedError - The caller is not the owner
+    /// * NegativeAmountError - The amount is negative
+    /// * ClaimsPaused - Claims are paused
+    /// * BeneficiaryLockup - The lockup has beneficiaries, who are paid with `claim`
+    pub fn claim_amount(e: Env, token: Address, amount: i128) -> i128 {
+        let owner = storage::get_owner(&e);
+        owner.require_auth();
+        require_not_paused(&e);
+        require_no_beneficiaries(&e);
+
+        if amount < 0 {
+            panic_with_error!(&e, TokenLockupError::NegativeAmountError);
         }
+
+        let unlocks = get_token_schedule(&e, &token);
+        claim_token(&e, &unlocks, &token, &owner, Some(amount))
     }
 }
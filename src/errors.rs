@@ -18,4 +18,17 @@ pub enum TokenLockupError {
     InvalidUnlocks = 100,
     NoUnlockedTokens = 101,
     AlreadyUnlocked = 102,
+    NoPendingAdmin = 103,
+    NoPendingOwner = 104,
+    AdminRenounced = 105,
+    LockupRevoked = 106,
+    NoPendingUpgrade = 107,
+    UpgradeNotReady = 108,
+    InvalidUpgradeDelay = 109,
+    ClaimsPaused = 110,
+    BeneficiaryLockup = 111,
+    InvalidShares = 112,
+    CallNotAllowed = 113,
+    PositionToken = 114,
+    TooManyTokens = 115,
 }
@@ -0,0 +1,356 @@
+use soroban_sdk::{Address, BytesN, Env, Map, String, Symbol};
+
+use crate::types::{LeaverPolicy, Schedule, TopUpPolicy};
+
+pub struct LockupEvents {}
+
+impl LockupEvents {
+    /// Emitted when the lockup is initialized
+    ///
+    /// - topics - `["initialize", admin: Address, owner: Address]`
+    /// - data - `unlocks: Schedule`
+    ///
+    /// ### Arguments
+    /// * `admin` - The admin of the lockup
+    /// * `owner` - The owner of the lockup
+    /// * `unlocks` - The unlocks of the lockup
+    pub fn initialize(e: &Env, admin: Address, owner: Address, unlocks: Schedule) {
+        let topics = (Symbol::new(e, "initialize"), admin, owner);
+        e.events().publish(topics, unlocks);
+    }
+
+    /// Emitted when the unlocks are changed by the admin
+    ///
+    /// - topics - `["set_unlocks"]`
+    /// - data - `[old_unlocks: Schedule, new_unlocks: Schedule]`
+    ///
+    /// ### Arguments
+    /// * `old_unlocks` - The unlocks before the change
+    /// * `new_unlocks` - The unlocks after the change
+    pub fn set_unlocks(e: &Env, old_unlocks: Schedule, new_unlocks: Schedule) {
+        let topics = (Symbol::new(e, "set_unlocks"),);
+        e.events().publish(topics, (old_unlocks, new_unlocks));
+    }
+
+    /// Emitted for each token claimed from the lockup
+    ///
+    /// - topics - `["claim", token: Address, to: Address]`
+    /// - data - `[amount: i128, timestamp: u64]`
+    ///
+    /// ### Arguments
+    /// * `token` - The token claimed
+    /// * `to` - The address the tokens were transferred to
+    /// * `amount` - The amount of tokens claimed
+    pub fn claim(e: &Env, token: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, "claim"), token, to);
+        e.events().publish(topics, (amount, e.ledger().timestamp()));
+    }
+
+    /// Emitted after a claim event when a memo is attached to the claim
+    ///
+    /// - topics - `["claim_memo", token: Address, to: Address]`
+    /// - data - `memo: String`
+    ///
+    /// ### Arguments
+    /// * `token` - The token claimed
+    /// * `to` - The address the tokens were transferred to
+    /// * `memo` - The memo attached to the claim
+    pub fn claim_memo(e: &Env, token: Address, to: Address, memo: String) {
+        let topics = (Symbol::new(e, "claim_memo"), token, to);
+        e.events().publish(topics, memo);
+    }
+
+    /// Emitted for each token revoked from the lockup
+    ///
+    /// - topics - `["revoke", token: Address, to: Address]`
+    /// - data - `[amount: i128, policy: LeaverPolicy]`
+    ///
+    /// ### Arguments
+    /// * `token` - The token revoked
+    /// * `to` - The address the unvested tokens were transferred to
+    /// * `amount` - The amount of tokens revoked
+    /// * `policy` - The leaver policy applied to the vested tokens
+    pub fn revoke(e: &Env, token: Address, to: Address, amount: i128, policy: LeaverPolicy) {
+        let topics = (Symbol::new(e, "revoke"), token, to);
+        e.events().publish(topics, (amount, policy));
+    }
+
+    /// Emitted when the unlocks of a single token are set
+    ///
+    /// - topics - `["set_token_unlocks", token: Address]`
+    /// - data - `[old_unlocks: Option<Schedule>, new_unlocks: Schedule]`
+    ///
+    /// ### Arguments
+    /// * `token` - The token the unlocks apply to
+    /// * `old_unlocks` - The token's unlocks before the change, or None if it used the lockup's unlocks
+    /// * `new_unlocks` - The token's unlocks after the change
+    pub fn set_token_unlocks(
+        e: &Env,
+        token: Address,
+        old_unlocks: Option<Schedule>,
+        new_unlocks: Schedule,
+    ) {
+        let topics = (Symbol::new(e, "set_token_unlocks"), token);
+        e.events().publish(topics, (old_unlocks, new_unlocks));
+    }
+
+    /// Emitted when tokens are deposited into the lockup
+    ///
+    /// - topics - `["deposit", token: Address, from: Address]`
+    /// - data - `[amount: i128, timestamp: u64]`
+    ///
+    /// ### Arguments
+    /// * `token` - The token deposited
+    /// * `from` - The address the tokens were transferred from
+    /// * `amount` - The amount of tokens deposited
+    pub fn deposit(e: &Env, token: Address, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, "deposit"), token, from);
+        e.events().publish(topics, (amount, e.ledger().timestamp()));
+    }
+
+    /// Emitted when the top-up policy is changed by the admin
+    ///
+    /// - topics - `["set_top_up_policy"]`
+    /// - data - `policy: TopUpPolicy`
+    ///
+    /// ### Arguments
+    /// * `policy` - The new top-up policy
+    pub fn set_top_up_policy(e: &Env, policy: TopUpPolicy) {
+        let topics = (Symbol::new(e, "set_top_up_policy"),);
+        e.events().publish(topics, policy);
+    }
+
+    /// Emitted when a new admin is proposed by the admin
+    ///
+    /// - topics - `["propose_admin"]`
+    /// - data - `new_admin: Address`
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The proposed admin
+    pub fn propose_admin(e: &Env, new_admin: Address) {
+        let topics = (Symbol::new(e, "propose_admin"),);
+        e.events().publish(topics, new_admin);
+    }
+
+    /// Emitted when a pending admin proposal is cancelled by the admin
+    ///
+    /// - topics - `["cancel_admin_proposal"]`
+    /// - data - `pending_admin: Address`
+    ///
+    /// ### Arguments
+    /// * `pending_admin` - The admin of the cancelled proposal
+    pub fn cancel_admin_proposal(e: &Env, pending_admin: Address) {
+        let topics = (Symbol::new(e, "cancel_admin_proposal"),);
+        e.events().publish(topics, pending_admin);
+    }
+
+    /// Emitted when the pending admin accepts the admin role
+    ///
+    /// - topics - `["accept_admin"]`
+    /// - data - `[old_admin: Address, new_admin: Address]`
+    ///
+    /// ### Arguments
+    /// * `old_admin` - The admin before the change
+    /// * `new_admin` - The admin after the change
+    pub fn accept_admin(e: &Env, old_admin: Address, new_admin: Address) {
+        let topics = (Symbol::new(e, "accept_admin"),);
+        e.events().publish(topics, (old_admin, new_admin));
+    }
+
+    /// Emitted when the admin role is renounced
+    ///
+    /// - topics - `["renounce_admin"]`
+    /// - data - `admin: Address`
+    ///
+    /// ### Arguments
+    /// * `admin` - The admin that renounced the role
+    pub fn renounce_admin(e: &Env, admin: Address) {
+        let topics = (Symbol::new(e, "renounce_admin"),);
+        e.events().publish(topics, admin);
+    }
+
+    /// Emitted when the owner is changed by the owner and admin
+    ///
+    /// - topics - `["set_owner"]`
+    /// - data - `[old_owner: Address, new_owner: Address]`
+    ///
+    /// ### Arguments
+    /// * `old_owner` - The owner before the change
+    /// * `new_owner` - The owner after the change
+    pub fn set_owner(e: &Env, old_owner: Address, new_owner: Address) {
+        let topics = (Symbol::new(e, "set_owner"),);
+        e.events().publish(topics, (old_owner, new_owner));
+    }
+
+    /// Emitted when a new owner is proposed by the admin
+    ///
+    /// - topics - `["propose_owner"]`
+    /// - data - `new_owner: Address`
+    ///
+    /// ### Arguments
+    /// * `new_owner` - The proposed owner
+    pub fn propose_owner(e: &Env, new_owner: Address) {
+        let topics = (Symbol::new(e, "propose_owner"),);
+        e.events().publish(topics, new_owner);
+    }
+
+    /// Emitted when a pending owner proposal is cancelled by the admin
+    ///
+    /// - topics - `["cancel_owner_proposal"]`
+    /// - data - `pending_owner: Address`
+    ///
+    /// ### Arguments
+    /// * `pending_owner` - The owner of the cancelled proposal
+    pub fn cancel_owner_proposal(e: &Env, pending_owner: Address) {
+        let topics = (Symbol::new(e, "cancel_owner_proposal"),);
+        e.events().publish(topics, pending_owner);
+    }
+
+    /// Emitted when the pending owner accepts ownership of the lockup
+    ///
+    /// - topics - `["accept_owner"]`
+    /// - data - `[old_owner: Address, new_owner: Address]`
+    ///
+    /// ### Arguments
+    /// * `old_owner` - The owner before the change
+    /// * `new_owner` - The owner after the change
+    pub fn accept_owner(e: &Env, old_owner: Address, new_owner: Address) {
+        let topics = (Symbol::new(e, "accept_owner"),);
+        e.events().publish(topics, (old_owner, new_owner));
+    }
+
+    /// Emitted when the admin sets the upgrade delay
+    ///
+    /// - topics - `["set_upgrade_delay"]`
+    /// - data - `delay: u64`
+    ///
+    /// ### Arguments
+    /// * `delay` - The new upgrade delay (in seconds)
+    pub fn set_upgrade_delay(e: &Env, delay: u64) {
+        let topics = (Symbol::new(e, "set_upgrade_delay"),);
+        e.events().publish(topics, delay);
+    }
+
+    /// Emitted when an upgrade is proposed by the admin
+    ///
+    /// - topics - `["propose_upgrade"]`
+    /// - data - `[wasm_hash: BytesN<32>, executable_at: u64]`
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the wasm the lockup will be upgraded to
+    /// * `executable_at` - The ledger time the upgrade can be executed
+    pub fn propose_upgrade(e: &Env, wasm_hash: BytesN<32>, executable_at: u64) {
+        let topics = (Symbol::new(e, "propose_upgrade"),);
+        e.events().publish(topics, (wasm_hash, executable_at));
+    }
+
+    /// Emitted when a pending upgrade is cancelled by the admin
+    ///
+    /// - topics - `["cancel_upgrade"]`
+    /// - data - `wasm_hash: BytesN<32>`
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the wasm of the cancelled upgrade
+    pub fn cancel_upgrade(e: &Env, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "cancel_upgrade"),);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when the lockup is upgraded
+    ///
+    /// - topics - `["upgrade"]`
+    /// - data - `wasm_hash: BytesN<32>`
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the wasm the lockup was upgraded to
+    pub fn upgrade(e: &Env, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "upgrade"),);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when claims are paused by the admin
+    ///
+    /// - topics - `["pause"]`
+    /// - data - `timestamp: u64`
+    pub fn pause(e: &Env) {
+        let topics = (Symbol::new(e, "pause"),);
+        e.events().publish(topics, e.ledger().timestamp());
+    }
+
+    /// Emitted when claims are unpaused by the admin
+    ///
+    /// - topics - `["unpause"]`
+    /// - data - `timestamp: u64`
+    pub fn unpause(e: &Env) {
+        let topics = (Symbol::new(e, "unpause"),);
+        e.events().publish(topics, e.ledger().timestamp());
+    }
+
+    /// Emitted when the lockup's votes for a token are delegated by the owner
+    ///
+    /// - topics - `["delegate", token: Address]`
+    /// - data - `delegatee: Address`
+    ///
+    /// ### Arguments
+    /// * `token` - The votes token
+    /// * `delegatee` - The address the votes were delegated to
+    pub fn delegate(e: &Env, token: Address, delegatee: Address) {
+        let topics = (Symbol::new(e, "delegate"), token);
+        e.events().publish(topics, delegatee);
+    }
+
+    /// Emitted when the admin allows or disallows a call from the lockup
+    ///
+    /// - topics - `["set_allowed_call", contract: Address, function: Symbol]`
+    /// - data - `allowed: bool`
+    ///
+    /// ### Arguments
+    /// * `contract` - The contract of the call
+    /// * `function` - The function of the call
+    /// * `allowed` - If the owner can invoke the call
+    pub fn set_allowed_call(e: &Env, contract: Address, function: Symbol, allowed: bool) {
+        let topics = (Symbol::new(e, "set_allowed_call"), contract, function);
+        e.events().publish(topics, allowed);
+    }
+
+    /// Emitted when the admin sets if a token is a position token, or a token received from a
+    /// call that deploys tokens is marked as a position token
+    ///
+    /// - topics - `["set_position_token", token: Address]`
+    /// - data - `is_position: bool`
+    ///
+    /// ### Arguments
+    /// * `token` - The token
+    /// * `is_position` - If the token is a position token
+    pub fn set_position_token(e: &Env, token: Address, is_position: bool) {
+        let topics = (Symbol::new(e, "set_position_token"), token);
+        e.events().publish(topics, is_position);
+    }
+
+    /// Emitted when the owner adds or removes a token from the lockup's registry
+    ///
+    /// - topics - `["set_registered_token", token: Address]`
+    /// - data - `registered: bool`
+    ///
+    /// ### Arguments
+    /// * `token` - The token
+    /// * `registered` - If the token is registered
+    pub fn set_registered_token(e: &Env, token: Address, registered: bool) {
+        let topics = (Symbol::new(e, "set_registered_token"), token);
+        e.events().publish(topics, registered);
+    }
+
+    /// Emitted when the owner invokes an allowlisted call from the lockup
+    ///
+    /// - topics - `["invoke", contract: Address, function: Symbol]`
+    /// - data - `deployed: Map<Address, i128>`
+    ///
+    /// ### Arguments
+    /// * `contract` - The contract invoked
+    /// * `function` - The function invoked
+    /// * `deployed` - The change in the deployed amount of each token
+    pub fn invoke(e: &Env, contract: Address, function: Symbol, deployed: Map<Address, i128>) {
+        let topics = (Symbol::new(e, "invoke"), contract, function);
+        e.events().publish(topics, deployed);
+    }
+}
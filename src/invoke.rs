@@ -0,0 +1,89 @@
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    panic_with_error,
+    token::TokenClient,
+    vec, Address, Env, IntoVal, Map, Symbol, Val, Vec,
+};
+
+use crate::{claim::settle_revoke, errors::TokenLockupError, events::LockupEvents, storage};
+
+/// Invoke an allowlisted call from the lockup. The called contract is authorized to transfer the
+/// deployment amount of each token from the lockup. The change in the lockup's balance of each
+/// token is recorded as deployed, so claims continue to account for tokens held by the contract.
+/// Tokens received from a call that deploys tokens are marked as position tokens. Once the lockup
+/// is revoked, tokens can only be returned, and any revoked tokens waiting on them are transferred.
+///
+/// Returns the result of the call, and the change in the deployed amount of each token
+///
+/// ### Arguments
+/// * `contract` - The contract to invoke
+/// * `function` - The function to invoke
+/// * `args` - The arguments to invoke the function with
+/// * `deployments` - The amount of each token the contract can transfer from the lockup
+pub fn invoke_call(
+    e: &Env,
+    contract: &Address,
+    function: &Symbol,
+    args: &Vec<Val>,
+    deployments: &Map<Address, i128>,
+) -> (Val, Map<Address, i128>) {
+    if !storage::get_allowed_call(e, contract, function) {
+        panic_with_error!(e, TokenLockupError::CallNotAllowed);
+    }
+
+    let lockup = e.current_contract_address();
+    let mut is_deploying = false;
+    let mut auths: Vec<InvokerContractAuthEntry> = Vec::new(e);
+    let mut balances: Map<Address, i128> = Map::new(e);
+    for (token, amount) in deployments.iter() {
+        if amount < 0 {
+            panic_with_error!(e, TokenLockupError::NegativeAmountError);
+        }
+        if amount > 0 && storage::get_revoked(e).is_some() {
+            panic_with_error!(e, TokenLockupError::LockupRevoked);
+        }
+        balances.set(token.clone(), TokenClient::new(e, &token).balance(&lockup));
+        if amount > 0 {
+            is_deploying = true;
+            auths.push_back(InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token,
+                    fn_name: Symbol::new(e, "transfer"),
+                    args: vec![
+                        e,
+                        lockup.into_val(e),
+                        contract.into_val(e),
+                        amount.into_val(e),
+                    ],
+                },
+                sub_invocations: Vec::new(e),
+            }));
+        }
+    }
+    if !auths.is_empty() {
+        e.authorize_as_current_contract(auths);
+    }
+
+    let result = e.invoke_contract::<Val>(contract, function, args.clone());
+
+    // tokens returned beyond the deployed amount, like yield, are added to the lockup's balance
+    let mut changes: Map<Address, i128> = Map::new(e);
+    for (token, prev_balance) in balances.iter() {
+        let balance = TokenClient::new(e, &token).balance(&lockup);
+        // tokens received for deployed tokens remain subject to the schedule
+        if is_deploying
+            && deployments.get(token.clone()) == Some(0)
+            && balance > prev_balance
+            && !storage::get_position_token(e, &token)
+        {
+            storage::set_position_token(e, &token, &true);
+            LockupEvents::set_position_token(e, token.clone(), true);
+        }
+        let prev_deployed = storage::get_deployed(e, &token);
+        let deployed = (prev_deployed + prev_balance - balance).max(0);
+        storage::set_deployed(e, &token, &deployed);
+        settle_revoke(e, &token);
+        changes.set(token, deployed - prev_deployed);
+    }
+    (result, changes)
+}
@@ -1,7 +1,10 @@
 #![no_std]
 
+mod claim;
 pub mod contract;
 mod errors;
+mod events;
+mod invoke;
 mod storage;
 mod types;
 mod validation;
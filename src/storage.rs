@@ -1,20 +1,75 @@
-use soroban_sdk::{Address, Env, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, Env, Map, Symbol, Vec};
 
-use crate::types::Unlock;
+use crate::types::{PendingRevoke, PendingUpgrade, Schedule, TopUpPolicy};
 
 /********** Ledger Thresholds **********/
 
 const ONE_DAY_LEDGERS: u32 = 17280; // assumes 5 seconds per ledger
+const ONE_WEEK_SECONDS: u64 = 7 * 24 * 60 * 60;
 
 const LEDGER_BUMP: u32 = 120 * ONE_DAY_LEDGERS;
 const LEDGER_THRESHOLD: u32 = LEDGER_BUMP - 20 * ONE_DAY_LEDGERS;
 
+/// The maximum number of tokens in the lockup's registry, so `claim_all` stays within budget
+pub const MAX_TOKENS: u32 = 20;
+
 /********** Ledger Keys **********/
 
 const OWNER_KEY: &str = "Owner";
+const PENDING_OWNER_KEY: &str = "PendingOwner";
 const ADMIN_KEY: &str = "Admin";
+const PENDING_ADMIN_KEY: &str = "PendingAdmin";
 const IS_INIT_KEY: &str = "IsInit";
 const UNLOCKS_KEY: &str = "Unlocks";
+const TOKENS_KEY: &str = "Tokens";
+const REVOKED_KEY: &str = "Revoked";
+const REVOKED_LEDGER_KEY: &str = "RevokedLedger";
+const TOP_UP_POLICY_KEY: &str = "TopUpPolicy";
+const UPGRADE_DELAY_KEY: &str = "UpgradeDelay";
+const PENDING_UPGRADE_KEY: &str = "PendingUpgrade";
+const PAUSED_KEY: &str = "Paused";
+const BENEFICIARIES_KEY: &str = "Beneficiaries";
+
+#[derive(Clone)]
+#[contracttype]
+pub enum LockupDataKey {
+    // The unlocked but unclaimed amount of a token
+    Unclaimed(Address),
+    // The unlock schedule of a token, if it does not use the lockup's schedule
+    TokenUnlocks(Address),
+    // The total amount of a token claimed from the lockup
+    Claimed(Address),
+    // The total amount of a token deposited with `deposit`
+    Deposited(Address),
+    // The balance of a token after the last claim
+    LastBalance(Address),
+    // The claim state of a beneficiary for a token
+    BeneficiaryClaim(Address, Address),
+    // Whether the owner can invoke a function of a contract from the lockup
+    AllowedCall(Address, Symbol),
+    // Whether a token is a position token received from an allowlisted call
+    PositionToken(Address),
+    // The amount of a token deployed to other contracts with allowlisted calls
+    Deployed(Address),
+    // The total amount of a token revoked from the lockup
+    Revoked(Address),
+    // The revoked amount of a token waiting on deployed tokens to be returned
+    PendingRevoke(Address),
+}
+
+/// The claim bookkeeping of a token, for the owner or for a single beneficiary
+#[derive(Clone)]
+#[contracttype]
+pub struct ClaimState {
+    /// The time of the last claim
+    pub last_claim: u64,
+    /// The amount unlocked but not claimed
+    pub unclaimed: i128,
+    /// The total amount claimed
+    pub claimed: i128,
+    /// The balance after the last claim
+    pub last_balance: i128,
+}
 
 /********** Ledger Thresholds **********/
 
@@ -51,30 +106,188 @@ pub fn get_owner(e: &Env) -> Address {
 pub fn set_owner(e: &Env, owner: &Address) {
     e.storage()
         .instance()
-        .set::<Symbol, Address>(&Symbol::new(e, OWNER_KEY), &owner);
+        .set::<Symbol, Address>(&Symbol::new(e, OWNER_KEY), owner);
+}
+
+/// Get the pending owner address, if an owner recovery has been proposed
+pub fn get_pending_owner(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<Symbol, Address>(&Symbol::new(e, PENDING_OWNER_KEY))
 }
 
-/// Get the admin address
-pub fn get_admin(e: &Env) -> Address {
+/// Set the pending owner address
+pub fn set_pending_owner(e: &Env, pending_owner: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, PENDING_OWNER_KEY), pending_owner);
+}
+
+/// Remove the pending owner address
+pub fn del_pending_owner(e: &Env) {
+    e.storage()
+        .instance()
+        .remove::<Symbol>(&Symbol::new(e, PENDING_OWNER_KEY));
+}
+
+/// Get the admin address, if the admin role has not been renounced
+pub fn get_admin(e: &Env) -> Option<Address> {
     e.storage()
         .instance()
         .get::<Symbol, Address>(&Symbol::new(e, ADMIN_KEY))
-        .unwrap()
 }
 
 /// Set the admin address
 pub fn set_admin(e: &Env, admin: &Address) {
     e.storage()
         .instance()
-        .set::<Symbol, Address>(&Symbol::new(e, ADMIN_KEY), &admin);
+        .set::<Symbol, Address>(&Symbol::new(e, ADMIN_KEY), admin);
+}
+
+/// Remove the admin address
+pub fn del_admin(e: &Env) {
+    e.storage()
+        .instance()
+        .remove::<Symbol>(&Symbol::new(e, ADMIN_KEY));
+}
+
+/// Get the pending admin address, if an admin transfer has been proposed
+pub fn get_pending_admin(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<Symbol, Address>(&Symbol::new(e, PENDING_ADMIN_KEY))
+}
+
+/// Set the pending admin address
+pub fn set_pending_admin(e: &Env, pending_admin: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, PENDING_ADMIN_KEY), pending_admin);
+}
+
+/// Remove the pending admin address
+pub fn del_pending_admin(e: &Env) {
+    e.storage()
+        .instance()
+        .remove::<Symbol>(&Symbol::new(e, PENDING_ADMIN_KEY));
+}
+
+/// Get the time the lockup was revoked, if it has been revoked
+pub fn get_revoked(e: &Env) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get::<Symbol, u64>(&Symbol::new(e, REVOKED_KEY))
+}
+
+/// Set the time the lockup was revoked
+pub fn set_revoked(e: &Env, time: &u64) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u64>(&Symbol::new(e, REVOKED_KEY), time);
+}
+
+/// Get the ledger sequence number the lockup was revoked at, if it has been revoked
+pub fn get_revoked_ledger(e: &Env) -> Option<u64> {
+    e.storage()
+        .instance()
+        .get::<Symbol, u64>(&Symbol::new(e, REVOKED_LEDGER_KEY))
+}
+
+/// Set the ledger sequence number the lockup was revoked at
+pub fn set_revoked_ledger(e: &Env, sequence: &u64) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u64>(&Symbol::new(e, REVOKED_LEDGER_KEY), sequence);
+}
+
+/// Get the top-up policy of the lockup. Defaults to `TopUpPolicy::Future`.
+pub fn get_top_up_policy(e: &Env) -> TopUpPolicy {
+    e.storage()
+        .instance()
+        .get::<Symbol, TopUpPolicy>(&Symbol::new(e, TOP_UP_POLICY_KEY))
+        .unwrap_or(TopUpPolicy::Future)
+}
+
+/// Set the top-up policy of the lockup
+pub fn set_top_up_policy(e: &Env, policy: &TopUpPolicy) {
+    e.storage()
+        .instance()
+        .set::<Symbol, TopUpPolicy>(&Symbol::new(e, TOP_UP_POLICY_KEY), policy);
+}
+
+/// Get the delay (in seconds) between proposing and executing an upgrade. Defaults to one week.
+pub fn get_upgrade_delay(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get::<Symbol, u64>(&Symbol::new(e, UPGRADE_DELAY_KEY))
+        .unwrap_or(ONE_WEEK_SECONDS)
+}
+
+/// Set the delay (in seconds) between proposing and executing an upgrade
+pub fn set_upgrade_delay(e: &Env, delay: &u64) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u64>(&Symbol::new(e, UPGRADE_DELAY_KEY), delay);
+}
+
+/// Get the pending upgrade, if an upgrade has been proposed
+pub fn get_pending_upgrade(e: &Env) -> Option<PendingUpgrade> {
+    e.storage()
+        .instance()
+        .get::<Symbol, PendingUpgrade>(&Symbol::new(e, PENDING_UPGRADE_KEY))
+}
+
+/// Set the pending upgrade
+pub fn set_pending_upgrade(e: &Env, pending_upgrade: &PendingUpgrade) {
+    e.storage()
+        .instance()
+        .set::<Symbol, PendingUpgrade>(&Symbol::new(e, PENDING_UPGRADE_KEY), pending_upgrade);
+}
+
+/// Remove the pending upgrade
+pub fn del_pending_upgrade(e: &Env) {
+    e.storage()
+        .instance()
+        .remove::<Symbol>(&Symbol::new(e, PENDING_UPGRADE_KEY));
+}
+
+/// Check if claims are paused
+pub fn get_paused(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get::<Symbol, bool>(&Symbol::new(e, PAUSED_KEY))
+        .unwrap_or(false)
+}
+
+/// Set if claims are paused
+pub fn set_paused(e: &Env, paused: &bool) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, PAUSED_KEY), paused);
+}
+
+/// Get the beneficiaries of the lockup and their shares (in bps). Empty if the lockup
+/// has a single owner.
+pub fn get_beneficiaries(e: &Env) -> Map<Address, u32> {
+    e.storage()
+        .instance()
+        .get::<Symbol, Map<Address, u32>>(&Symbol::new(e, BENEFICIARIES_KEY))
+        .unwrap_or(Map::new(e))
+}
+
+/// Set the beneficiaries of the lockup and their shares (in bps)
+pub fn set_beneficiaries(e: &Env, beneficiaries: &Map<Address, u32>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Map<Address, u32>>(&Symbol::new(e, BENEFICIARIES_KEY), beneficiaries);
 }
 
 /********** Persistant **********/
 
-/// Get the times of the lockup unlocks
-pub fn get_unlocks(e: &Env) -> Option<Vec<Unlock>> {
+/// Get the unlock schedule of the lockup
+pub fn get_unlocks(e: &Env) -> Option<Schedule> {
     let key = Symbol::new(e, UNLOCKS_KEY);
-    let result = e.storage().persistent().get::<Symbol, Vec<Unlock>>(&key);
+    let result = e.storage().persistent().get::<Symbol, Schedule>(&key);
     if result.is_some() {
         e.storage()
             .persistent()
@@ -83,12 +296,89 @@ pub fn get_unlocks(e: &Env) -> Option<Vec<Unlock>> {
     result
 }
 
-/// Set the times of the lockup unlocks
-pub fn set_unlocks(e: &Env, unlocks: &Vec<Unlock>) {
+/// Set the unlock schedule of the lockup
+pub fn set_unlocks(e: &Env, unlocks: &Schedule) {
     let key = Symbol::new(e, UNLOCKS_KEY);
     e.storage()
         .persistent()
-        .set::<Symbol, Vec<Unlock>>(&key, unlocks);
+        .set::<Symbol, Schedule>(&key, unlocks);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the tokens that have been deposited into or claimed from the lockup
+pub fn get_tokens(e: &Env) -> Vec<Address> {
+    let key = Symbol::new(e, TOKENS_KEY);
+    let result = e.storage().persistent().get::<Symbol, Vec<Address>>(&key);
+    match result {
+        Some(tokens) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            tokens
+        }
+        None => Vec::new(e),
+    }
+}
+
+/// Add a token to the tokens that have been deposited into or claimed from the lockup, if
+/// it has not already been added and the registry is not full
+///
+/// Returns if the token is registered
+pub fn add_token(e: &Env, token: &Address) -> bool {
+    let mut tokens = get_tokens(e);
+    if tokens.contains(token) {
+        return true;
+    }
+    if tokens.len() >= MAX_TOKENS {
+        return false;
+    }
+    tokens.push_back(token.clone());
+    set_tokens(e, &tokens);
+    true
+}
+
+/// Remove a token from the tokens that have been deposited into or claimed from the lockup
+pub fn remove_token(e: &Env, token: &Address) {
+    let mut tokens = get_tokens(e);
+    if let Some(index) = tokens.first_index_of(token) {
+        tokens.remove(index);
+        set_tokens(e, &tokens);
+    }
+}
+
+fn set_tokens(e: &Env, tokens: &Vec<Address>) {
+    let key = Symbol::new(e, TOKENS_KEY);
+    e.storage()
+        .persistent()
+        .set::<Symbol, Vec<Address>>(&key, tokens);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the unlock schedule of a token, if the token has its own schedule
+pub fn get_token_unlocks(e: &Env, token: &Address) -> Option<Schedule> {
+    let key = LockupDataKey::TokenUnlocks(token.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<LockupDataKey, Schedule>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+    result
+}
+
+/// Set the unlock schedule of a token
+pub fn set_token_unlocks(e: &Env, token: &Address, unlocks: &Schedule) {
+    let key = LockupDataKey::TokenUnlocks(token.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, Schedule>(&key, unlocks);
     e.storage()
         .persistent()
         .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
@@ -96,12 +386,12 @@ pub fn set_unlocks(e: &Env, unlocks: &Vec<Unlock>) {
 
 /// Get the last claim time for a token
 pub fn get_last_claim(e: &Env, token: &Address) -> u64 {
-    let result = e.storage().persistent().get::<Address, u64>(&token);
+    let result = e.storage().persistent().get::<Address, u64>(token);
     match result {
         Some(last_claim) => {
             e.storage()
                 .persistent()
-                .extend_ttl(&token, LEDGER_THRESHOLD, LEDGER_BUMP);
+                .extend_ttl(token, LEDGER_THRESHOLD, LEDGER_BUMP);
             last_claim
         }
         None => 0,
@@ -110,8 +400,300 @@ pub fn get_last_claim(e: &Env, token: &Address) -> u64 {
 
 /// Set the last claim time for a token
 pub fn set_last_claim(e: &Env, token: &Address, time: &u64) {
-    e.storage().persistent().set::<Address, u64>(&token, time);
+    e.storage().persistent().set::<Address, u64>(token, time);
+    e.storage()
+        .persistent()
+        .extend_ttl(token, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the amount of a token that has been unlocked but not claimed
+pub fn get_unclaimed(e: &Env, token: &Address) -> i128 {
+    let key = LockupDataKey::Unclaimed(token.clone());
+    let result = e.storage().persistent().get::<LockupDataKey, i128>(&key);
+    match result {
+        Some(unclaimed) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            unclaimed
+        }
+        None => 0,
+    }
+}
+
+/// Set the amount of a token that has been unlocked but not claimed
+pub fn set_unclaimed(e: &Env, token: &Address, amount: &i128) {
+    let key = LockupDataKey::Unclaimed(token.clone());
     e.storage()
         .persistent()
-        .extend_ttl(&token, LEDGER_THRESHOLD, LEDGER_BUMP);
+        .set::<LockupDataKey, i128>(&key, amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the total amount of a token that has been claimed
+pub fn get_claimed(e: &Env, token: &Address) -> i128 {
+    let key = LockupDataKey::Claimed(token.clone());
+    let result = e.storage().persistent().get::<LockupDataKey, i128>(&key);
+    match result {
+        Some(claimed) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            claimed
+        }
+        None => 0,
+    }
+}
+
+/// Set the total amount of a token that has been claimed
+pub fn set_claimed(e: &Env, token: &Address, amount: &i128) {
+    let key = LockupDataKey::Claimed(token.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, i128>(&key, amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the total amount of a token that has been deposited
+pub fn get_deposited(e: &Env, token: &Address) -> i128 {
+    let key = LockupDataKey::Deposited(token.clone());
+    let result = e.storage().persistent().get::<LockupDataKey, i128>(&key);
+    match result {
+        Some(deposited) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            deposited
+        }
+        None => 0,
+    }
+}
+
+/// Set the total amount of a token that has been deposited
+pub fn set_deposited(e: &Env, token: &Address, amount: &i128) {
+    let key = LockupDataKey::Deposited(token.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, i128>(&key, amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the total amount of a token that has been revoked
+pub fn get_revoked_amount(e: &Env, token: &Address) -> i128 {
+    let key = LockupDataKey::Revoked(token.clone());
+    let result = e.storage().persistent().get::<LockupDataKey, i128>(&key);
+    match result {
+        Some(revoked) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            revoked
+        }
+        None => 0,
+    }
+}
+
+/// Set the total amount of a token that has been revoked
+pub fn set_revoked_amount(e: &Env, token: &Address, amount: &i128) {
+    let key = LockupDataKey::Revoked(token.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, i128>(&key, amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the balance of a token after the last claim
+pub fn get_last_balance(e: &Env, token: &Address) -> i128 {
+    let key = LockupDataKey::LastBalance(token.clone());
+    let result = e.storage().persistent().get::<LockupDataKey, i128>(&key);
+    match result {
+        Some(last_balance) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            last_balance
+        }
+        None => 0,
+    }
+}
+
+/// Set the balance of a token after the last claim
+pub fn set_last_balance(e: &Env, token: &Address, amount: &i128) {
+    let key = LockupDataKey::LastBalance(token.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, i128>(&key, amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the claim state of a token for the owner
+pub fn get_claim_state(e: &Env, token: &Address) -> ClaimState {
+    ClaimState {
+        last_claim: get_last_claim(e, token),
+        unclaimed: get_unclaimed(e, token),
+        claimed: get_claimed(e, token),
+        last_balance: get_last_balance(e, token),
+    }
+}
+
+/// Set the claim state of a token for the owner
+pub fn set_claim_state(e: &Env, token: &Address, state: &ClaimState) {
+    set_last_claim(e, token, &state.last_claim);
+    set_unclaimed(e, token, &state.unclaimed);
+    set_claimed(e, token, &state.claimed);
+    set_last_balance(e, token, &state.last_balance);
+}
+
+/// Get the claim state of a token for a beneficiary
+pub fn get_beneficiary_claim(e: &Env, beneficiary: &Address, token: &Address) -> ClaimState {
+    let key = LockupDataKey::BeneficiaryClaim(beneficiary.clone(), token.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<LockupDataKey, ClaimState>(&key);
+    match result {
+        Some(state) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            state
+        }
+        None => ClaimState {
+            last_claim: 0,
+            unclaimed: 0,
+            claimed: 0,
+            last_balance: 0,
+        },
+    }
+}
+
+/// Set the claim state of a token for a beneficiary
+pub fn set_beneficiary_claim(e: &Env, beneficiary: &Address, token: &Address, state: &ClaimState) {
+    let key = LockupDataKey::BeneficiaryClaim(beneficiary.clone(), token.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, ClaimState>(&key, state);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Check if the owner can invoke a function of a contract from the lockup
+pub fn get_allowed_call(e: &Env, contract: &Address, function: &Symbol) -> bool {
+    let key = LockupDataKey::AllowedCall(contract.clone(), function.clone());
+    let result = e.storage().persistent().get::<LockupDataKey, bool>(&key);
+    match result {
+        Some(allowed) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            allowed
+        }
+        None => false,
+    }
+}
+
+/// Set if the owner can invoke a function of a contract from the lockup
+pub fn set_allowed_call(e: &Env, contract: &Address, function: &Symbol, allowed: &bool) {
+    let key = LockupDataKey::AllowedCall(contract.clone(), function.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, bool>(&key, allowed);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Check if a token is a position token
+pub fn get_position_token(e: &Env, token: &Address) -> bool {
+    let key = LockupDataKey::PositionToken(token.clone());
+    let result = e.storage().persistent().get::<LockupDataKey, bool>(&key);
+    match result {
+        Some(is_position) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            is_position
+        }
+        None => false,
+    }
+}
+
+/// Set if a token is a position token
+pub fn set_position_token(e: &Env, token: &Address, is_position: &bool) {
+    let key = LockupDataKey::PositionToken(token.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, bool>(&key, is_position);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the amount of a token deployed to other contracts
+pub fn get_deployed(e: &Env, token: &Address) -> i128 {
+    let key = LockupDataKey::Deployed(token.clone());
+    let result = e.storage().persistent().get::<LockupDataKey, i128>(&key);
+    match result {
+        Some(deployed) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+            deployed
+        }
+        None => 0,
+    }
+}
+
+/// Set the amount of a token deployed to other contracts
+pub fn set_deployed(e: &Env, token: &Address, amount: &i128) {
+    let key = LockupDataKey::Deployed(token.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, i128>(&key, amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Get the revoked amount of a token waiting on deployed tokens to be returned
+pub fn get_pending_revoke(e: &Env, token: &Address) -> Option<PendingRevoke> {
+    let key = LockupDataKey::PendingRevoke(token.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<LockupDataKey, PendingRevoke>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+    result
+}
+
+/// Set the revoked amount of a token waiting on deployed tokens to be returned
+pub fn set_pending_revoke(e: &Env, token: &Address, pending_revoke: &PendingRevoke) {
+    let key = LockupDataKey::PendingRevoke(token.clone());
+    e.storage()
+        .persistent()
+        .set::<LockupDataKey, PendingRevoke>(&key, pending_revoke);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+}
+
+/// Remove the pending revoke of a token
+pub fn del_pending_revoke(e: &Env, token: &Address) {
+    let key = LockupDataKey::PendingRevoke(token.clone());
+    e.storage().persistent().remove::<LockupDataKey>(&key);
 }
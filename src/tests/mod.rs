@@ -1,3 +1,24 @@
+mod test_admin;
+mod test_beneficiaries;
 mod test_claim;
+mod test_claim_all;
+mod test_claim_amount;
+mod test_claim_amount_unlocks;
+mod test_claim_cliff_linear;
+mod test_claim_cumulative;
+mod test_claim_ledger;
+mod test_claim_linear;
+mod test_claim_periodic;
+mod test_claim_to;
+mod test_claimable;
+mod test_delegate;
+mod test_deposit;
 mod test_initialize;
+mod test_invoke;
+mod test_owner;
+mod test_pause;
+mod test_revoke;
 mod test_set_unlocks;
+mod test_token_unlocks;
+mod test_top_up;
+mod test_upgrade;
@@ -0,0 +1,259 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    contract::TokenLockupClient,
+    testutils::{register_token_lockup, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+fn setup_lockup<'a>(e: &Env, admin: &Address, owner: &Address) -> TokenLockupClient<'a> {
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        e,
+        Unlock {
+            time: now + 10000,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 20000,
+            percent: 10000,
+        },
+    ]);
+
+    let (salt, lockup_id) = register_token_lockup(e, admin);
+    let lockup_client = TokenLockupClient::new(e, &lockup_id);
+    lockup_client.initialize(admin, &salt, admin, owner, &unlocks, &map![e], &map![e]);
+    lockup_client
+}
+
+#[test]
+fn test_lockup_admin_transfer() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let gandalf = Address::generate(&e);
+
+    let lockup_client = setup_lockup(&e, &bombadil, &frodo);
+    assert_eq!(lockup_client.pending_admin(), None);
+
+    // propose a new admin
+    lockup_client.propose_admin(&gandalf);
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "propose_admin"),
+                    vec![&e, gandalf.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_client.address.clone(),
+                (Symbol::new(&e, "propose_admin"),).into_val(&e),
+                gandalf.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.pending_admin(), Some(gandalf.clone()));
+    assert_eq!(lockup_client.admin(), Some(bombadil.clone()));
+
+    // accept the admin role
+    lockup_client.accept_admin();
+    assert_eq!(
+        e.auths()[0],
+        (
+            gandalf.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "accept_admin"),
+                    vec![&e]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_client.address.clone(),
+                (Symbol::new(&e, "accept_admin"),).into_val(&e),
+                (bombadil.clone(), gandalf.clone()).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.admin(), Some(gandalf));
+    assert_eq!(lockup_client.pending_admin(), None);
+
+    // validate the admin transfer can't be accepted twice
+    let result = lockup_client.try_accept_admin();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(103))));
+}
+
+#[test]
+fn test_lockup_admin_transfer_cancel() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let gandalf = Address::generate(&e);
+    let saruman = Address::generate(&e);
+
+    let lockup_client = setup_lockup(&e, &bombadil, &frodo);
+
+    // nothing to cancel
+    let result = lockup_client.try_cancel_admin_proposal();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(103))));
+
+    // a new proposal replaces the previous one
+    lockup_client.propose_admin(&saruman);
+    lockup_client.propose_admin(&gandalf);
+    assert_eq!(lockup_client.pending_admin(), Some(gandalf.clone()));
+
+    lockup_client.cancel_admin_proposal();
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "cancel_admin_proposal"),
+                    vec![&e]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_client.address.clone(),
+                (Symbol::new(&e, "cancel_admin_proposal"),).into_val(&e),
+                gandalf.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.pending_admin(), None);
+
+    let result = lockup_client.try_accept_admin();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(103))));
+    assert_eq!(lockup_client.admin(), Some(bombadil));
+}
+
+#[test]
+fn test_lockup_renounce_admin() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let gandalf = Address::generate(&e);
+    let samwise = Address::generate(&e);
+
+    let lockup_client = setup_lockup(&e, &bombadil, &frodo);
+    lockup_client.propose_admin(&gandalf);
+    lockup_client.propose_owner(&samwise);
+
+    lockup_client.renounce_admin();
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "renounce_admin"),
+                    vec![&e]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_client.address.clone(),
+                (Symbol::new(&e, "renounce_admin"),).into_val(&e),
+                bombadil.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.admin(), None);
+    assert_eq!(lockup_client.pending_admin(), None);
+    assert_eq!(lockup_client.pending_owner(), None);
+
+    // validate the unlocks can no longer be changed
+    let now = e.ledger().timestamp();
+    let new_unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+    let result = lockup_client.try_set_unlocks(&new_unlocks, &None);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+
+    // validate admin functions are disabled
+    let result = lockup_client.try_propose_admin(&gandalf);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+    let result = lockup_client.try_accept_admin();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(103))));
+    let result = lockup_client.try_propose_owner(&samwise);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+    let result = lockup_client.try_renounce_admin();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+
+    // validate the owner can still be changed by the owner alone
+    lockup_client.set_owner(&samwise);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "set_owner"),
+                    vec![&e, samwise.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(lockup_client.owner(), samwise);
+}
@@ -0,0 +1,326 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, map,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, Map, Symbol,
+};
+
+use crate::{
+    contract::TokenLockupClient,
+    testutils::{register_token_lockup, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+/// A token that rejects transfers to blocked addresses
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn mint(e: Env, to: Address, amount: i128) {
+        let balance = Self::balance(e.clone(), to.clone()) + amount;
+        e.storage().instance().set(&to, &balance);
+    }
+
+    pub fn set_blocked(e: Env, id: Address, blocked: bool) {
+        e.storage()
+            .instance()
+            .set(&(Symbol::new(&e, "blocked"), id), &blocked);
+    }
+
+    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let blocked: bool = e
+            .storage()
+            .instance()
+            .get(&(Symbol::new(&e, "blocked"), to.clone()))
+            .unwrap_or(false);
+        if blocked {
+            panic!("blocked");
+        }
+        let from_balance = Self::balance(e.clone(), from.clone()) - amount;
+        e.storage().instance().set(&from, &from_balance);
+        let to_balance = Self::balance(e.clone(), to.clone()) + amount;
+        e.storage().instance().set(&to, &to_balance);
+    }
+
+    pub fn balance(e: Env, id: Address) -> i128 {
+        e.storage().instance().get(&id).unwrap_or(0)
+    }
+}
+
+fn setup_shared_lockup<'a>(
+    e: &Env,
+    admin: &Address,
+    owner: &Address,
+    unlocks: &Schedule,
+    beneficiaries: &Map<Address, u32>,
+) -> TokenLockupClient<'a> {
+    let (salt, lockup_id) = register_token_lockup(e, admin);
+    let lockup_client = TokenLockupClient::new(e, &lockup_id);
+    lockup_client.initialize(admin, &salt, admin, owner, unlocks, &map![e], beneficiaries);
+    lockup_client
+}
+
+#[test]
+fn test_lockup_claim_beneficiaries() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let pippin = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+    let beneficiaries = map![
+        &e,
+        (frodo.clone(), 5000),
+        (samwise.clone(), 3000),
+        (pippin.clone(), 2000)
+    ];
+    let lockup_client = setup_shared_lockup(&e, &bombadil, &frodo, &unlocks, &beneficiaries);
+    let lockup_id = lockup_client.address.clone();
+    assert_eq!(lockup_client.beneficiaries(), beneficiaries);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    // validate the owner amounts can't be read from a shared lockup
+    e.jump_time_only(100); // t = 100
+    let result = lockup_client.try_claimable(&tokens);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(111))));
+    assert_eq!(
+        lockup_client.claimable_share(&samwise, &tokens),
+        vec![&e, token_1_total * 3 / 20]
+    );
+
+    // validate each beneficiary receives their share of the first unlock
+    lockup_client.claim(&tokens);
+    assert_eq!(e.auths(), std::vec![]);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 4);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total * 3 / 20);
+    assert_eq!(token_1_client.balance(&pippin), token_1_total / 10);
+    assert_eq!(
+        lockup_client.claimable_share(&samwise, &tokens),
+        vec![&e, 0]
+    );
+
+    // validate the owner's claim functions are disabled
+    let result = lockup_client.try_claim_to(&tokens, &frodo, &None);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(111))));
+    let result = lockup_client.try_claim_amount(&token_1_id, &1);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(111))));
+
+    e.jump_time_only(100); // t = 200
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total * 3 / 10);
+    assert_eq!(token_1_client.balance(&pippin), token_1_total / 5);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+    assert_eq!(lockup_client.claimed(&token_1_id), token_1_total);
+
+    // validate non-beneficiaries have no share
+    let result = lockup_client.try_claimable_share(&bombadil, &tokens);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+}
+
+#[test]
+fn test_lockup_claim_beneficiaries_linear() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Linear(vec![
+        &e,
+        Unlock {
+            time: now,
+            percent: 0,
+        },
+        Unlock {
+            time: now + 1000,
+            percent: 10000,
+        },
+    ]);
+    let beneficiaries = map![&e, (frodo.clone(), 5000), (samwise.clone(), 5000)];
+    let lockup_client = setup_shared_lockup(&e, &bombadil, &frodo, &unlocks, &beneficiaries);
+    let lockup_id = lockup_client.address.clone();
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    e.jump_time_only(250); // t = 250
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 8);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total / 8);
+
+    e.jump_time_only(250); // t = 500
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 4);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total / 4);
+
+    e.jump_time_only(500); // t = 1000
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
+
+#[test]
+fn test_lockup_claim_beneficiaries_unable_to_receive() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_contract(None, MockToken {});
+    let token_1_client = MockTokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+    let beneficiaries = map![&e, (frodo.clone(), 5000), (samwise.clone(), 5000)];
+    let lockup_client = setup_shared_lockup(&e, &bombadil, &frodo, &unlocks, &beneficiaries);
+    let lockup_id = lockup_client.address.clone();
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    // validate a beneficiary that can't receive the token does not block the others
+    token_1_client.set_blocked(&samwise, &true);
+    e.jump_time_only(100); // t = 100
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&samwise), 0);
+    assert_eq!(
+        lockup_client.claimable_share(&samwise, &tokens),
+        vec![&e, token_1_total / 2]
+    );
+
+    token_1_client.set_blocked(&samwise, &false);
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
+
+#[test]
+fn test_lockup_claim_beneficiaries_nothing_unlocked() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+    let beneficiaries = map![&e, (frodo.clone(), 5000), (samwise.clone(), 5000)];
+    let lockup_client = setup_shared_lockup(&e, &bombadil, &frodo, &unlocks, &beneficiaries);
+    let lockup_id = lockup_client.address.clone();
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    // a third party claims before anything unlocks
+    e.jump_time_only(50); // t = 50
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 0);
+    assert_eq!(token_1_client.balance(&samwise), 0);
+
+    // validate a deposit after the first unlock still vests on the full schedule
+    e.jump_time_only(100); // t = 150
+    token_1_admin_client.mint(&bombadil, &token_1_total);
+    lockup_client.deposit(&bombadil, &token_1_id, &token_1_total);
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total / 2);
+}
+
+#[test]
+fn test_lockup_initialize_invalid_shares() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+
+    let (salt, lockup_id) = register_token_lockup(&e, &bombadil);
+    let lockup_client = TokenLockupClient::new(&e, &lockup_id);
+    let result = lockup_client.try_initialize(
+        &bombadil,
+        &salt,
+        &bombadil,
+        &frodo,
+        &unlocks,
+        &map![&e],
+        &map![&e, (frodo.clone(), 5000), (samwise.clone(), 4000)],
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(112))));
+}
@@ -1,14 +1,15 @@
 #![cfg(test)]
 
 use soroban_sdk::{
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
     token::{StellarAssetClient, TokenClient},
-    vec, Address, Env, IntoVal, Symbol, Vec,
+    vec, Address, Env, IntoVal, Symbol,
 };
 
 use crate::{
     testutils::{create_token_lockup_wasm, EnvTestUtils},
-    types::Unlock,
+    types::{Schedule, Unlock},
 };
 
 #[test]
@@ -31,7 +32,7 @@ fn test_lockup_claim() {
     // half of tokens at first traunch after 10k seconds
     // split the remaining half into 10 equal installments every 1k seconds
     // for a total of 11 traunches
-    let unlocks: Vec<Unlock> = vec![
+    let unlocks = Schedule::Step(vec![
         &e,
         Unlock {
             time: now + 10000,
@@ -77,9 +78,10 @@ fn test_lockup_claim() {
             time: now + 20000,
             percent: 10000,
         },
-    ];
+    ]);
 
-    let (lockup_id, lockup_client) = create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks);
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
 
     // send tokens to lockup
     let token_1_total: i128 = 1_000 * 10i128.pow(7);
@@ -126,6 +128,31 @@ fn test_lockup_claim() {
     assert_eq!(token_1_client.balance(&lockup_id), token_1_total / 2);
     assert_eq!(token_2_client.balance(&lockup_id), token_2_total / 2);
 
+    // validate a claim event is emitted for each token after the transfer
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.get_unchecked(events.len() - 3)],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "claim"), token_1_id.clone(), frodo.clone()).into_val(&e),
+                (token_1_total / 2, e.ledger().timestamp()).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(
+        vec![&e, events.get_unchecked(events.len() - 1)],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "claim"), token_2_id.clone(), frodo.clone()).into_val(&e),
+                (token_2_total / 2, e.ledger().timestamp()).into_val(&e)
+            )
+        ]
+    );
+
     // verify duplicate claims within the same traunch don't send tokens
     e.jump_time_only(999); // t = 10999
     lockup_client.claim(&vec![&e, token_1_id.clone(), token_2_id.clone()]);
@@ -270,7 +297,7 @@ fn test_lockup_claim_at_end() {
     // half of tokens at first traunch after 10k seconds
     // split the remaining half into 10 equal installments every 1k seconds
     // for a total of 11 traunches
-    let unlocks: Vec<Unlock> = vec![
+    let unlocks = Schedule::Step(vec![
         &e,
         Unlock {
             time: now + 10000,
@@ -316,9 +343,10 @@ fn test_lockup_claim_at_end() {
             time: now + 20000,
             percent: 10000,
         },
-    ];
+    ]);
 
-    let (lockup_id, lockup_client) = create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks);
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
 
     // send tokens to lockup
     let token_1_total: i128 = 1_000 * 10i128.pow(7);
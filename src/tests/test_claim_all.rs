@@ -0,0 +1,251 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+/// A token whose balance panics once it is broken, and whose transfers panic once it is frozen
+#[contract]
+pub struct MockBrokenToken;
+
+#[contractimpl]
+impl MockBrokenToken {
+    pub fn mint(e: Env, to: Address, amount: i128) {
+        let balance = Self::balance(e.clone(), to.clone()) + amount;
+        e.storage().instance().set(&to, &balance);
+    }
+
+    pub fn set_broken(e: Env, broken: bool) {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "broken"), &broken);
+    }
+
+    pub fn set_frozen(e: Env, frozen: bool) {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "frozen"), &frozen);
+    }
+
+    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let frozen: bool = e
+            .storage()
+            .instance()
+            .get(&Symbol::new(&e, "frozen"))
+            .unwrap_or(false);
+        if frozen {
+            panic!("frozen");
+        }
+        let from_balance = Self::balance(e.clone(), from.clone()) - amount;
+        e.storage().instance().set(&from, &from_balance);
+        let to_balance = Self::balance(e.clone(), to.clone()) + amount;
+        e.storage().instance().set(&to, &to_balance);
+    }
+
+    pub fn balance(e: Env, id: Address) -> i128 {
+        let broken: bool = e
+            .storage()
+            .instance()
+            .get(&Symbol::new(&e, "broken"))
+            .unwrap_or(false);
+        if broken {
+            panic!("broken");
+        }
+        e.storage().instance().get(&id).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_lockup_claim_all() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+    let token_2_client = TokenClient::new(&e, &token_2_id);
+    let token_3_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_3_admin_client = StellarAssetClient::new(&e, &token_3_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+    assert_eq!(lockup_client.tokens(), vec![&e]);
+
+    // token 1 is deposited, and tokens 2 and 3 are transferred directly
+    let token_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&bombadil, &token_total);
+    lockup_client.deposit(&bombadil, &token_1_id, &token_total);
+    token_2_admin_client.mint(&lockup_id, &token_total);
+    token_3_admin_client.mint(&lockup_id, &token_total);
+    assert_eq!(lockup_client.tokens(), vec![&e, token_1_id.clone()]);
+
+    // validate tokens are recorded once they are claimed
+    e.jump_time_only(100); // t = 100
+    lockup_client.claim(&vec![&e, token_2_id.clone(), token_1_id.clone()]);
+    assert_eq!(
+        lockup_client.tokens(),
+        vec![&e, token_1_id.clone(), token_2_id.clone()]
+    );
+
+    e.jump_time_only(100); // t = 200
+    lockup_client.claim_all();
+    assert_eq!(
+        e.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_id.clone(),
+                    Symbol::new(&e, "claim_all"),
+                    vec![&e]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(token_1_client.balance(&frodo), token_total);
+    assert_eq!(token_2_client.balance(&frodo), token_total);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+    assert_eq!(token_2_client.balance(&lockup_id), 0);
+
+    // validate tokens that were never deposited or claimed are not claimed
+    assert_eq!(TokenClient::new(&e, &token_3_id).balance(&frodo), 0);
+}
+
+#[test]
+fn test_lockup_claim_all_broken_token() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let gollum = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_id = e.register_stellar_asset_contract(gollum.clone());
+    let bad_token_id = e.register_contract(None, MockBrokenToken {});
+    let bad_token_client = MockBrokenTokenClient::new(&e, &bad_token_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&bombadil, &token_total);
+    lockup_client.deposit(&bombadil, &token_1_id, &token_total);
+
+    // validate deposits from third parties, or that do not fund the lockup, do not register tokens
+    lockup_client.deposit(&bombadil, &token_2_id, &0);
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+    token_2_admin_client.mint(&gollum, &token_total);
+    lockup_client.deposit(&gollum, &token_2_id, &token_total);
+    bad_token_client.mint(&gollum, &1);
+    lockup_client.deposit(&gollum, &bad_token_id, &1);
+    assert_eq!(lockup_client.tokens(), vec![&e, token_1_id.clone()]);
+
+    // the admin deposits a token, which is later frozen
+    bad_token_client.mint(&bombadil, &token_total);
+    lockup_client.deposit(&bombadil, &bad_token_id, &token_total);
+    assert_eq!(
+        lockup_client.tokens(),
+        vec![&e, token_1_id.clone(), bad_token_id.clone()]
+    );
+    bad_token_client.set_frozen(&true);
+
+    // validate the frozen token does not block claims of the other tokens
+    e.jump_time_only(100); // t = 100
+    lockup_client.claim_all();
+    assert_eq!(token_1_client.balance(&frodo), token_total);
+    assert_eq!(bad_token_client.balance(&frodo), 0);
+    assert_eq!(bad_token_client.balance(&lockup_id), token_total + 1);
+
+    // validate a token with a broken balance does not block claims of the other tokens
+    token_1_admin_client.mint(&lockup_id, &token_total);
+    bad_token_client.set_broken(&true);
+    lockup_client.claim_all();
+    assert_eq!(token_1_client.balance(&frodo), token_total * 2);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+
+    // the owner removes the broken token
+    lockup_client.set_registered_token(&bad_token_id, &false);
+    assert_eq!(
+        e.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_id.clone(),
+                    Symbol::new(&e, "set_registered_token"),
+                    vec![&e, bad_token_id.into_val(&e), false.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (
+                    Symbol::new(&e, "set_registered_token"),
+                    bad_token_id.clone()
+                )
+                    .into_val(&e),
+                false.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.tokens(), vec![&e, token_1_id.clone()]);
+
+    // validate the registry is bounded
+    lockup_client.set_registered_token(&token_2_id, &true);
+    for _ in 2..20 {
+        let token_id = e.register_stellar_asset_contract(gollum.clone());
+        lockup_client.set_registered_token(&token_id, &true);
+    }
+    assert_eq!(lockup_client.tokens().len(), 20);
+    let result = lockup_client.try_set_registered_token(&bad_token_id, &true);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(115))));
+}
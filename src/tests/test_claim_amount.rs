@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_claim_amount() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 300,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    // claim part of the first unlock
+    e.jump_time_only(150); // t = 150
+    let claimed = lockup_client.claim_amount(&token_1_id, &(200 * 10i128.pow(7)));
+    assert_eq!(
+        e.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "claim_amount"),
+                    vec![
+                        &e,
+                        token_1_id.into_val(&e),
+                        (200 * 10i128.pow(7)).into_val(&e)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(claimed, 200 * 10i128.pow(7));
+    assert_eq!(token_1_client.balance(&frodo), 200 * 10i128.pow(7));
+    assert_eq!(token_1_client.balance(&lockup_id), 800 * 10i128.pow(7));
+
+    // validate the remainder of the first unlock is still claimable
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, 300 * 10i128.pow(7)]
+    );
+
+    // validate the second unlock only applies to tokens that were not yet unlocked, and
+    // that the claim is capped at the claimable amount
+    e.jump_time_only(100); // t = 250
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, 550 * 10i128.pow(7)]
+    );
+    let claimed = lockup_client.claim_amount(&token_1_id, &token_1_total);
+    assert_eq!(claimed, 550 * 10i128.pow(7));
+    assert_eq!(token_1_client.balance(&frodo), 750 * 10i128.pow(7));
+    assert_eq!(token_1_client.balance(&lockup_id), 250 * 10i128.pow(7));
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0]);
+
+    // validate negative amounts are rejected
+    let result = lockup_client.try_claim_amount(&token_1_id, &-1);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(8))));
+
+    // claim part of the final unlock, then claim the rest
+    e.jump_time_only(50); // t = 300
+    let claimed = lockup_client.claim_amount(&token_1_id, &(50 * 10i128.pow(7)));
+    assert_eq!(claimed, 50 * 10i128.pow(7));
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, 200 * 10i128.pow(7)]
+    );
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
+
+#[test]
+fn test_lockup_claim_amount_then_claim() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 2500,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+
+    // claim nothing, which still records the unlocked amount
+    e.jump_time_only(100); // t = 100
+    let claimed = lockup_client.claim_amount(&token_1_id, &0);
+    assert_eq!(claimed, 0);
+    assert_eq!(token_1_client.balance(&frodo), 0);
+
+    // validate a full claim includes the previously unlocked tokens
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 4);
+    assert_eq!(token_1_client.balance(&lockup_id), 3 * token_1_total / 4);
+}
@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, Vec,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{AmountUnlock, Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_claim_amount_unlocks() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+    // 7 equal unlocks of 100 tokens every 100 seconds
+    let mut amount_unlocks: Vec<AmountUnlock> = vec![&e];
+    for i in 1..=7 {
+        amount_unlocks.push_back(AmountUnlock {
+            time: now + i * 100,
+            amount: 100 * 10i128.pow(7),
+        });
+    }
+    let token_1_unlocks = Schedule::Amount(amount_unlocks);
+
+    let (lockup_id, lockup_client) = create_token_lockup_wasm(
+        &e,
+        &bombadil,
+        &frodo,
+        &unlocks,
+        &map![&e, (token_1_id.clone(), token_1_unlocks.clone())],
+    );
+
+    // validate amount unlocks can't be used as the lockup's unlocks
+    let result = lockup_client.try_set_unlocks(&token_1_unlocks, &None);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(100))));
+
+    // underfund the lockup
+    token_1_admin_client.mint(&lockup_id, &(500 * 10i128.pow(7)));
+    let tokens = vec![&e, token_1_id.clone()];
+
+    // validate the unlock amount does not depend on the balance
+    e.jump_time_only(150); // t = 150
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 100 * 10i128.pow(7));
+
+    // validate the claim is capped by the balance, and the rest remains owed
+    e.jump_time_only(500); // t = 650
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, 400 * 10i128.pow(7)]
+    );
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 500 * 10i128.pow(7));
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+
+    token_1_admin_client.mint(&lockup_id, &(300 * 10i128.pow(7)));
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, 100 * 10i128.pow(7)]
+    );
+
+    // validate the surplus balance is claimable after the final unlock
+    e.jump_time_only(50); // t = 700
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, 300 * 10i128.pow(7)]
+    );
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 800 * 10i128.pow(7));
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
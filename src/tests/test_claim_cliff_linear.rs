@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{CliffLinear, Schedule},
+};
+
+#[test]
+fn test_lockup_claim_cliff_linear() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_2_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_client = TokenClient::new(&e, &token_2_id);
+
+    let now = e.ledger().timestamp();
+    // start accruing after 100 seconds, with a 20% cliff at 400 seconds, and the remaining
+    // tokens accrued by 1100 seconds
+    let unlocks = Schedule::CliffLinear(CliffLinear {
+        start: now + 100,
+        cliff: now + 400,
+        end: now + 1100,
+        cliff_percent: 2000,
+    });
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    let token_2_total: i128 = 400_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    token_2_admin_client.mint(&lockup_id, &token_2_total);
+    let tokens = vec![&e, token_1_id.clone(), token_2_id.clone()];
+
+    // nothing can be claimed before the cliff
+    e.jump_time_only(399); // t = 399
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0, 0]);
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 0);
+    assert_eq!(token_2_client.balance(&frodo), 0);
+
+    // the cliff percent and the tokens accrued since the start can be claimed at the cliff
+    e.jump_time_only(1); // t = 400
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(token_1_client.balance(&frodo), 440 * 10i128.pow(7));
+    assert_eq!(token_1_client.balance(&lockup_id), 560 * 10i128.pow(7));
+
+    // claim halfway through the schedule. Validate token 2 does not miss any accrual
+    // from before the cliff.
+    e.jump_time_only(200); // t = 600
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 600 * 10i128.pow(7));
+    assert_eq!(token_1_client.balance(&lockup_id), 400 * 10i128.pow(7));
+    assert_eq!(token_2_client.balance(&frodo), 240_000 * 10i128.pow(7));
+    assert_eq!(token_2_client.balance(&lockup_id), 160_000 * 10i128.pow(7));
+
+    // claim the rest at the end
+    e.jump_time_only(500); // t = 1100
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total);
+    assert_eq!(token_2_client.balance(&frodo), token_2_total);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+    assert_eq!(token_2_client.balance(&lockup_id), 0);
+}
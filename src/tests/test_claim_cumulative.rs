@@ -0,0 +1,78 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_claim_cumulative() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    // 25% of the grant at 100 seconds, 50% at 200 seconds, and the full grant at 400 seconds
+    let unlocks = Schedule::Cumulative(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 2500,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 400,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_deposit: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_deposit);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    e.jump_time_only(100); // t = 100
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_deposit / 4);
+
+    // validate the unlock percent applies to all tokens ever deposited
+    e.jump_time_only(50); // t = 150
+    token_1_admin_client.mint(&lockup_id, &token_1_deposit);
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, token_1_deposit / 4]
+    );
+
+    e.jump_time_only(50); // t = 200
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_deposit);
+    assert_eq!(token_1_client.balance(&lockup_id), token_1_deposit);
+
+    // nothing new unlocks between unlocks
+    e.jump_time_only(100); // t = 300
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0]);
+
+    e.jump_time_only(100); // t = 400
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 2 * token_1_deposit);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
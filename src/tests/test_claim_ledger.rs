@@ -0,0 +1,172 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{LeaverPolicy, Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_claim_ledger_step() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let sequence = e.ledger().sequence() as u64;
+    let unlocks = Schedule::LedgerStep(vec![
+        &e,
+        Unlock {
+            time: sequence + 1000,
+            percent: 5000,
+        },
+        Unlock {
+            time: sequence + 2000,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    // validate the ledger time does not unlock tokens
+    e.jump_time_only(1000 * 5);
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0]);
+
+    e.jump(999); // sequence = 1099
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0]);
+
+    e.jump(1); // sequence = 1100
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+
+    e.jump(1000); // sequence = 2100
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
+
+#[test]
+fn test_lockup_claim_ledger_linear() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let sequence = e.ledger().sequence() as u64;
+    let unlocks = Schedule::LedgerLinear(vec![
+        &e,
+        Unlock {
+            time: sequence,
+            percent: 0,
+        },
+        Unlock {
+            time: sequence + 1000,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    e.jump(250); // sequence = 350
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 4);
+
+    // validate a revocation stops unlocks at the ledger it occured
+    e.jump(250); // sequence = 600
+    lockup_client.revoke(&vec![&e], &bombadil, &LeaverPolicy::Good);
+
+    e.jump(500); // sequence = 1100
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+}
+
+#[test]
+fn test_lockup_claim_ledger_token_unlocks() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 1000 * 5,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_total);
+    token_2_admin_client.mint(&lockup_id, &token_total);
+
+    // validate a token that has not been claimed can be given a ledger schedule
+    let sequence = e.ledger().sequence() as u64;
+    let ledger_unlocks = Schedule::LedgerStep(vec![
+        &e,
+        Unlock {
+            time: sequence + 2000,
+            percent: 10000,
+        },
+    ]);
+    lockup_client.set_unlocks(&ledger_unlocks, &Some(token_1_id.clone()));
+    assert_eq!(
+        lockup_client.token_unlocks(&token_1_id),
+        Some(ledger_unlocks.clone())
+    );
+
+    // validate a token claimed on the lockup's schedule can't switch to a ledger schedule
+    e.jump(1000); // sequence = 1100
+    lockup_client.claim(&vec![&e, token_2_id.clone()]);
+    let result = lockup_client.try_set_unlocks(&ledger_unlocks, &Some(token_2_id.clone()));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(100))));
+
+    // validate the token unlocks on its ledger schedule
+    assert_eq!(
+        lockup_client.claimable(&vec![&e, token_1_id.clone()]),
+        vec![&e, 0]
+    );
+
+    e.jump(1000); // sequence = 2100
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(token_1_client.balance(&frodo), token_total);
+}
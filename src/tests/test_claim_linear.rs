@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_claim_linear() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_2_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_client = TokenClient::new(&e, &token_2_id);
+
+    let now = e.ledger().timestamp();
+    // start accruing after 100 seconds, with half of the tokens accrued by 200 seconds and
+    // the remaining tokens accrued by 300 seconds
+    let unlocks = Schedule::Linear(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 0,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 300,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    let token_2_total: i128 = 400_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    token_2_admin_client.mint(&lockup_id, &token_2_total);
+    let tokens = vec![&e, token_1_id.clone(), token_2_id.clone()];
+
+    // nothing accrues before the start
+    e.jump_time_only(100); // t = 100
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 0);
+    assert_eq!(token_2_client.balance(&frodo), 0);
+
+    // claim halfway through the first period
+    e.jump_time_only(50); // t = 150
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 4);
+    assert_eq!(token_1_client.balance(&lockup_id), 3 * token_1_total / 4);
+
+    // claim again within the first period
+    e.jump_time_only(25); // t = 175
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(token_1_client.balance(&frodo), 3 * token_1_total / 8);
+    assert_eq!(token_1_client.balance(&lockup_id), 5 * token_1_total / 8);
+
+    // claim halfway through the second period. Validate token 2 does not miss any accrual
+    // from the first period.
+    e.jump_time_only(75); // t = 250
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 3 * token_1_total / 4);
+    assert_eq!(token_1_client.balance(&lockup_id), token_1_total / 4);
+    assert_eq!(token_2_client.balance(&frodo), 3 * token_2_total / 4);
+    assert_eq!(token_2_client.balance(&lockup_id), token_2_total / 4);
+
+    // claim the rest after the final unlock
+    e.jump_time_only(50); // t = 300
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total);
+    assert_eq!(token_2_client.balance(&frodo), token_2_total);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+    assert_eq!(token_2_client.balance(&lockup_id), 0);
+}
+
+#[test]
+fn test_lockup_claim_linear_with_cliff() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    // 25% unlocks at 1000 seconds, then the remaining tokens accrue until 4000 seconds
+    let unlocks = Schedule::Linear(vec![
+        &e,
+        Unlock {
+            time: now + 1000,
+            percent: 2500,
+        },
+        Unlock {
+            time: now + 4000,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_200 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    e.jump_time_only(999); // t = 999
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0]);
+
+    // cliff
+    e.jump_time_only(1); // t = 1000
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, token_1_total / 4]
+    );
+
+    // a third of the way through the linear period
+    e.jump_time_only(1000); // t = 2000
+    lockup_client.claim_amount(&token_1_id, &(token_1_total / 4));
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, token_1_total / 4]
+    );
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+
+    // two thirds of the way through the linear period
+    e.jump_time_only(1000); // t = 3000
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 3 * token_1_total / 4);
+
+    e.jump_time_only(1000); // t = 4000
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
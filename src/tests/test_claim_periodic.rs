@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Periodic, Schedule},
+};
+
+const ONE_WEEK: u64 = 7 * 24 * 60 * 60;
+
+#[test]
+fn test_lockup_claim_periodic() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    // weekly unlocks over four years, with a one year cliff
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Periodic(Periodic {
+        start: now,
+        period: ONE_WEEK,
+        periods: 208,
+        cliff_periods: 52,
+    });
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 208_000 * 10i128.pow(7);
+    let token_1_weekly: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    e.jump_time_only(52 * ONE_WEEK - 1);
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0]);
+
+    // the periods before the cliff unlock at the cliff
+    e.jump_time_only(1); // t = week 52
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 52 * token_1_weekly);
+
+    // nothing unlocks until the end of the next period
+    e.jump_time_only(ONE_WEEK / 2);
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0]);
+
+    e.jump_time_only(ONE_WEEK); // t = week 53.5
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 53 * token_1_weekly);
+
+    e.jump_time_only(100 * ONE_WEEK); // t = week 153.5
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 153 * token_1_weekly);
+
+    e.jump_time_only(55 * ONE_WEEK); // t = week 208.5
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, IntoVal, String, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_claim_to() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_2_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_client = TokenClient::new(&e, &token_2_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    let token_2_total: i128 = 400_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    token_2_admin_client.mint(&lockup_id, &token_2_total);
+    let tokens = vec![&e, token_1_id.clone(), token_2_id.clone()];
+
+    // claim first unlock to samwise with a memo
+    e.jump_time_only(150); // t = 150
+    let memo = String::from_str(&e, "deposit-1234");
+    lockup_client.claim_to(&tokens, &samwise, &Some(memo.clone()));
+
+    // validate claim_to requires owner signature
+    assert_eq!(
+        e.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "claim_to"),
+                    vec![
+                        &e,
+                        tokens.into_val(&e),
+                        samwise.into_val(&e),
+                        Some(memo.clone()).into_val(&e),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(token_1_client.balance(&samwise), token_1_total / 2);
+    assert_eq!(token_2_client.balance(&samwise), token_2_total / 2);
+    assert_eq!(token_1_client.balance(&frodo), 0);
+    assert_eq!(token_2_client.balance(&frodo), 0);
+    assert_eq!(token_1_client.balance(&lockup_id), token_1_total / 2);
+    assert_eq!(token_2_client.balance(&lockup_id), token_2_total / 2);
+
+    // validate the memo is emitted after each claim event
+    let events = e.events().all();
+    assert_eq!(
+        vec![
+            &e,
+            events.get_unchecked(events.len() - 5),
+            events.get_unchecked(events.len() - 4),
+            events.get_unchecked(events.len() - 2),
+            events.get_unchecked(events.len() - 1)
+        ],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (
+                    Symbol::new(&e, "claim"),
+                    token_1_id.clone(),
+                    samwise.clone()
+                )
+                    .into_val(&e),
+                (token_1_total / 2, e.ledger().timestamp()).into_val(&e)
+            ),
+            (
+                lockup_id.clone(),
+                (
+                    Symbol::new(&e, "claim_memo"),
+                    token_1_id.clone(),
+                    samwise.clone()
+                )
+                    .into_val(&e),
+                memo.into_val(&e)
+            ),
+            (
+                lockup_id.clone(),
+                (
+                    Symbol::new(&e, "claim"),
+                    token_2_id.clone(),
+                    samwise.clone()
+                )
+                    .into_val(&e),
+                (token_2_total / 2, e.ledger().timestamp()).into_val(&e)
+            ),
+            (
+                lockup_id.clone(),
+                (
+                    Symbol::new(&e, "claim_memo"),
+                    token_2_id.clone(),
+                    samwise.clone()
+                )
+                    .into_val(&e),
+                memo.into_val(&e)
+            )
+        ]
+    );
+
+    // validate the claimed unlock can't be claimed again by the owner
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 0);
+    assert_eq!(token_2_client.balance(&frodo), 0);
+
+    // claim final unlock to samwise without a memo
+    e.jump_time_only(50); // t = 200
+    lockup_client.claim_to(&vec![&e, token_1_id.clone()], &samwise, &None);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (
+                    Symbol::new(&e, "claim"),
+                    token_1_id.clone(),
+                    samwise.clone()
+                )
+                    .into_val(&e),
+                (token_1_total / 2, e.ledger().timestamp()).into_val(&e)
+            )
+        ]
+    );
+}
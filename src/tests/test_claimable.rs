@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_claimable_matches_claim() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_2_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_client = TokenClient::new(&e, &token_2_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 2500,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000 / 3,
+        },
+        Unlock {
+            time: now + 300,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    // send tokens to lockup
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    let token_2_total: i128 = 123_456 * 10i128.pow(7) + 7;
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    token_2_admin_client.mint(&lockup_id, &token_2_total);
+    let tokens = vec![&e, token_1_id.clone(), token_2_id.clone()];
+
+    // nothing claimable before the first unlock
+    e.jump_time_only(50); // t = 50
+    let claimable = lockup_client.claimable(&tokens);
+    assert_eq!(claimable, vec![&e, 0, 0]);
+
+    // validate claimable does not require auth
+    assert_eq!(e.auths().len(), 0);
+
+    // first unlock
+    e.jump_time_only(100); // t = 150
+    let claimable = lockup_client.claimable(&tokens);
+    assert_eq!(claimable, vec![&e, token_1_total / 4, token_2_total / 4]);
+
+    // claimable does not modify the lockup
+    let claimable_again = lockup_client.claimable(&tokens);
+    assert_eq!(claimable, claimable_again);
+    assert_eq!(token_1_client.balance(&lockup_id), token_1_total);
+    assert_eq!(token_2_client.balance(&lockup_id), token_2_total);
+
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), claimable.get_unchecked(0));
+    assert_eq!(token_2_client.balance(&frodo), claimable.get_unchecked(1));
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0, 0]);
+
+    // second unlock, only claim token 1
+    e.jump_time_only(100); // t = 250
+    let claimable = lockup_client.claimable(&tokens);
+    let token_1_pre_claim = token_1_client.balance(&frodo);
+    let token_2_pre_claim = token_2_client.balance(&frodo);
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(
+        token_1_client.balance(&frodo) - token_1_pre_claim,
+        claimable.get_unchecked(0)
+    );
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, 0, claimable.get_unchecked(1)]
+    );
+
+    // final unlock includes the entire balance
+    e.jump_time_only(50); // t = 300
+    let claimable = lockup_client.claimable(&tokens);
+    assert_eq!(
+        claimable,
+        vec![
+            &e,
+            token_1_client.balance(&lockup_id),
+            token_2_client.balance(&lockup_id)
+        ]
+    );
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total);
+    assert_eq!(
+        token_2_client.balance(&frodo) - token_2_pre_claim,
+        claimable.get_unchecked(1)
+    );
+    assert_eq!(token_2_client.balance(&frodo), token_2_total);
+}
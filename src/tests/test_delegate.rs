@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+/// A votes token that only records delegations
+#[contract]
+pub struct MockVotesToken;
+
+#[contractimpl]
+impl MockVotesToken {
+    pub fn delegate(e: Env, account: Address, delegatee: Address) {
+        account.require_auth();
+        e.storage().instance().set(&account, &delegatee);
+    }
+
+    pub fn delegates(e: Env, account: Address) -> Address {
+        e.storage().instance().get(&account).unwrap_or(account)
+    }
+}
+
+#[test]
+fn test_lockup_delegate() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let votes_id = e.register_contract(None, MockVotesToken {});
+    let votes_client = MockVotesTokenClient::new(&e, &votes_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+    assert_eq!(votes_client.delegates(&lockup_id), lockup_id);
+
+    lockup_client.delegate(&votes_id, &samwise);
+    assert_eq!(
+        e.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_id.clone(),
+                    Symbol::new(&e, "delegate"),
+                    vec![&e, votes_id.into_val(&e), samwise.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(votes_client.delegates(&lockup_id), samwise);
+
+    // validate delegate event
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "delegate"), votes_id.clone()).into_val(&e),
+                samwise.into_val(&e)
+            )
+        ]
+    );
+
+    // validate the delegation can be changed
+    lockup_client.delegate(&votes_id, &frodo);
+    assert_eq!(votes_client.delegates(&lockup_id), frodo);
+}
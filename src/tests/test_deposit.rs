@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_deposit() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&samwise, &token_1_total);
+
+    lockup_client.deposit(&samwise, &token_1_id, &token_1_total);
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "deposit"),
+                    vec![
+                        &e,
+                        samwise.into_val(&e),
+                        token_1_id.into_val(&e),
+                        token_1_total.into_val(&e)
+                    ]
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        token_1_id.clone(),
+                        Symbol::new(&e, "transfer"),
+                        vec![
+                            &e,
+                            samwise.into_val(&e),
+                            lockup_id.into_val(&e),
+                            token_1_total.into_val(&e)
+                        ]
+                    )),
+                    sub_invocations: std::vec![]
+                }]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (
+                    Symbol::new(&e, "deposit"),
+                    token_1_id.clone(),
+                    samwise.clone()
+                )
+                    .into_val(&e),
+                (token_1_total, e.ledger().timestamp()).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(token_1_client.balance(&samwise), 0);
+    assert_eq!(token_1_client.balance(&lockup_id), token_1_total);
+    assert_eq!(lockup_client.deposited(&token_1_id), token_1_total);
+    assert_eq!(lockup_client.claimed(&token_1_id), 0);
+
+    // validate negative amounts are rejected
+    let result = lockup_client.try_deposit(&samwise, &token_1_id, &-1);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(8))));
+
+    // validate direct transfers are not recorded as deposits
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    assert_eq!(lockup_client.deposited(&token_1_id), token_1_total);
+
+    e.jump_time_only(150); // t = 150
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(lockup_client.claimed(&token_1_id), token_1_total);
+    assert_eq!(lockup_client.deposited(&token_1_id), token_1_total);
+}
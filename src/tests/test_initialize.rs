@@ -1,11 +1,15 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, vec, Address, Env, Error, Vec};
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Map, Symbol,
+};
 
 use crate::{
-    contract::{TokenLockup, TokenLockupClient},
-    testutils::EnvTestUtils,
-    types::Unlock,
+    contract::TokenLockupClient,
+    testutils::{register_token_lockup, EnvTestUtils},
+    types::{AmountUnlock, Schedule, Unlock},
 };
 
 #[test]
@@ -21,7 +25,7 @@ fn test_lockup_initialize_validates_unlocks() {
     let now = e.ledger().timestamp();
     // half of current tokens at first cliff, then equal payments of the remaining half in
     // 10 installments every 1k seconds
-    let unlocks: Vec<Unlock> = vec![
+    let unlocks = Schedule::Step(vec![
         &e,
         Unlock {
             time: now + 10000,
@@ -31,12 +35,39 @@ fn test_lockup_initialize_validates_unlocks() {
             time: now + 20000,
             percent: 10000 - 1,
         },
-    ];
+    ]);
 
-    let lockup_id = e.register_contract(None, TokenLockup {});
+    let (salt, lockup_id) = register_token_lockup(&e, &bombadil);
     let lockup_client = TokenLockupClient::new(&e, &lockup_id);
 
-    let result = lockup_client.try_initialize(&bombadil, &frodo, &unlocks);
+    let result = lockup_client.try_initialize(
+        &bombadil,
+        &salt,
+        &bombadil,
+        &frodo,
+        &unlocks,
+        &map![&e],
+        &map![&e],
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(100))));
+
+    // validate amount unlocks can't be used as the lockup's unlocks
+    let amount_unlocks = Schedule::Amount(vec![
+        &e,
+        AmountUnlock {
+            time: now + 10000,
+            amount: 100,
+        },
+    ]);
+    let result = lockup_client.try_initialize(
+        &bombadil,
+        &salt,
+        &bombadil,
+        &frodo,
+        &amount_unlocks,
+        &map![&e],
+        &map![&e],
+    );
     assert_eq!(result.err(), Some(Ok(Error::from_contract_error(100))));
 }
 
@@ -53,7 +84,7 @@ fn test_lockup_initialize_once() {
     let now = e.ledger().timestamp();
     // half of current tokens at first cliff, then equal payments of the remaining half in
     // 10 installments every 1k seconds
-    let unlocks: Vec<Unlock> = vec![
+    let unlocks = Schedule::Step(vec![
         &e,
         Unlock {
             time: now + 10000,
@@ -63,20 +94,141 @@ fn test_lockup_initialize_once() {
             time: now + 20000,
             percent: 10000,
         },
-    ];
+    ]);
 
-    let lockup_id = e.register_contract(None, TokenLockup {});
+    let (salt, lockup_id) = register_token_lockup(&e, &bombadil);
     let lockup_client = TokenLockupClient::new(&e, &lockup_id);
 
-    lockup_client.initialize(&bombadil, &frodo, &unlocks);
+    lockup_client.initialize(
+        &bombadil,
+        &salt,
+        &bombadil,
+        &frodo,
+        &unlocks,
+        &map![&e],
+        &map![&e],
+    );
 
-    let new_unlocks: Vec<Unlock> = vec![
+    // validate initialize event
+    let events = e.events().all();
+    assert_eq!(
+        events,
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (
+                    Symbol::new(&e, "initialize"),
+                    bombadil.clone(),
+                    frodo.clone()
+                )
+                    .into_val(&e),
+                unlocks.into_val(&e)
+            )
+        ]
+    );
+
+    let new_unlocks = Schedule::Step(vec![
         &e,
         Unlock {
             time: now,
             percent: 10000,
         },
-    ];
-    let result = lockup_client.try_initialize(&bombadil, &frodo, &new_unlocks);
+    ]);
+    let result = lockup_client.try_initialize(
+        &bombadil,
+        &salt,
+        &bombadil,
+        &frodo,
+        &new_unlocks,
+        &map![&e],
+        &map![&e],
+    );
     assert_eq!(result.err(), Some(Ok(Error::from_contract_error(3))));
 }
+
+#[test]
+fn test_lockup_initialize_front_run() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let gollum = Address::generate(&e);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 10000,
+            percent: 10000,
+        },
+    ]);
+
+    let (salt, lockup_id) = register_token_lockup(&e, &bombadil);
+    let lockup_client = TokenLockupClient::new(&e, &lockup_id);
+
+    // validate an attacker can't initialize the lockup as the deployer
+    let result = lockup_client.try_initialize(
+        &gollum,
+        &salt,
+        &gollum,
+        &gollum,
+        &unlocks,
+        &map![&e],
+        &map![&e],
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(4))));
+
+    // validate an attacker can't initialize the lockup without the deployer's authorization
+    e.set_auths(&[]);
+    let result = lockup_client.try_initialize(
+        &bombadil,
+        &salt,
+        &gollum,
+        &gollum,
+        &unlocks,
+        &map![&e],
+        &map![&e],
+    );
+    assert!(result.is_err());
+
+    // validate the deployer can initialize the lockup
+    e.mock_all_auths();
+    lockup_client.initialize(
+        &bombadil,
+        &salt,
+        &bombadil,
+        &frodo,
+        &unlocks,
+        &map![&e],
+        &map![&e],
+    );
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "initialize"),
+                    vec![
+                        &e,
+                        bombadil.into_val(&e),
+                        salt.into_val(&e),
+                        bombadil.into_val(&e),
+                        frodo.into_val(&e),
+                        unlocks.into_val(&e),
+                        Map::<Address, Schedule>::new(&e).into_val(&e),
+                        Map::<Address, u32>::new(&e).into_val(&e)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(lockup_client.admin(), Some(bombadil));
+    assert_eq!(lockup_client.owner(), frodo);
+}
@@ -0,0 +1,458 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, IntoVal, Symbol, Val, Vec,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{LeaverPolicy, PendingRevoke, Schedule, Unlock},
+};
+
+/// A lending pool that issues shares for a single token. Withdrawals redeem shares for their
+/// portion of the pool's balance.
+#[contract]
+pub struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn set_token(e: Env, token: Address) {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "token"), &token);
+    }
+
+    pub fn supply(e: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let token: Address = e
+            .storage()
+            .instance()
+            .get(&Symbol::new(&e, "token"))
+            .unwrap();
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+        let shares = Self::balance(e.clone(), from.clone()) + amount;
+        e.storage().instance().set(&from, &shares);
+        let total = Self::total(&e) + amount;
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "total"), &total);
+    }
+
+    pub fn withdraw(e: Env, to: Address, shares: i128) {
+        to.require_auth();
+        let token: Address = e
+            .storage()
+            .instance()
+            .get(&Symbol::new(&e, "token"))
+            .unwrap();
+        let token_client = TokenClient::new(&e, &token);
+        let total = Self::total(&e);
+        let amount = token_client.balance(&e.current_contract_address()) * shares / total;
+        let remaining = Self::balance(e.clone(), to.clone()) - shares;
+        e.storage().instance().set(&to, &remaining);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "total"), &(total - shares));
+        token_client.transfer(&e.current_contract_address(), &to, &amount);
+    }
+
+    pub fn balance(e: Env, id: Address) -> i128 {
+        e.storage().instance().get(&id).unwrap_or(0)
+    }
+
+    fn total(e: &Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(e, "total"))
+            .unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_lockup_invoke() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let pool_id = e.register_contract(None, MockPool {});
+    let pool_client = MockPoolClient::new(&e, &pool_id);
+    pool_client.set_token(&token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+    let supply = Symbol::new(&e, "supply");
+    let withdraw = Symbol::new(&e, "withdraw");
+    let supply_amount = token_1_total * 6 / 10;
+    let supply_args: Vec<Val> = vec![&e, lockup_id.into_val(&e), supply_amount.into_val(&e)];
+
+    // validate calls must be allowed by the admin
+    let result = lockup_client.try_invoke(
+        &pool_id,
+        &supply,
+        &supply_args,
+        &map![&e, (token_1_id.clone(), supply_amount)],
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(113))));
+
+    lockup_client.set_allowed_call(&pool_id, &supply, &true);
+    lockup_client.set_allowed_call(&pool_id, &withdraw, &true);
+    assert!(lockup_client.allowed_call(&pool_id, &supply));
+    assert!(!lockup_client.position_token(&pool_id));
+
+    // supply tokens to the pool
+    let supply_deployments = map![
+        &e,
+        (token_1_id.clone(), supply_amount),
+        (pool_id.clone(), 0)
+    ];
+    lockup_client.invoke(&pool_id, &supply, &supply_args, &supply_deployments);
+    assert_eq!(
+        e.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_id.clone(),
+                    Symbol::new(&e, "invoke"),
+                    vec![
+                        &e,
+                        pool_id.into_val(&e),
+                        supply.into_val(&e),
+                        supply_args.into_val(&e),
+                        supply_deployments.into_val(&e)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(pool_client.balance(&lockup_id), supply_amount);
+    assert_eq!(lockup_client.deployed(&token_1_id), supply_amount);
+
+    // validate the pool shares received are marked as a position token
+    assert!(lockup_client.position_token(&pool_id));
+    assert_eq!(lockup_client.deployed(&pool_id), 0);
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.get_unchecked(events.len() - 2)],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "set_position_token"), pool_id.clone()).into_val(&e),
+                true.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(
+        token_1_client.balance(&lockup_id),
+        token_1_total - supply_amount
+    );
+
+    // validate deployed tokens still unlock, but only the balance can be claimed
+    e.jump_time_only(100); // t = 100
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, token_1_total - supply_amount]
+    );
+    lockup_client.claim(&tokens);
+    assert_eq!(
+        token_1_client.balance(&frodo),
+        token_1_total - supply_amount
+    );
+
+    // validate position tokens can't be claimed
+    let result = lockup_client.try_claim(&vec![&e, pool_id.clone()]);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(114))));
+    assert_eq!(
+        lockup_client.claimable(&vec![&e, pool_id.clone()]),
+        vec![&e, 0]
+    );
+
+    // withdraw the tokens with yield
+    let pool_yield = token_1_total / 10;
+    token_1_admin_client.mint(&pool_id, &pool_yield);
+    lockup_client.invoke(
+        &pool_id,
+        &withdraw,
+        &vec![&e, lockup_id.into_val(&e), supply_amount.into_val(&e)],
+        &map![&e, (token_1_id.clone(), 0)],
+    );
+    assert_eq!(pool_client.balance(&lockup_id), 0);
+    assert_eq!(lockup_client.deployed(&token_1_id), 0);
+    assert_eq!(
+        token_1_client.balance(&lockup_id),
+        supply_amount + pool_yield
+    );
+
+    // the unlocked tokens that were deployed can now be claimed
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, token_1_total / 2 - (token_1_total - supply_amount)]
+    );
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+
+    e.jump_time_only(100); // t = 200
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total + pool_yield);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
+
+#[test]
+fn test_lockup_invoke_revoke() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let pool_id = e.register_contract(None, MockPool {});
+    let pool_client = MockPoolClient::new(&e, &pool_id);
+    pool_client.set_token(&token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+    let supply = Symbol::new(&e, "supply");
+    let withdraw = Symbol::new(&e, "withdraw");
+    let supply_amount = token_1_total * 6 / 10;
+    let supply_args: Vec<Val> = vec![&e, lockup_id.into_val(&e), supply_amount.into_val(&e)];
+    lockup_client.set_allowed_call(&pool_id, &supply, &true);
+    lockup_client.set_allowed_call(&pool_id, &withdraw, &true);
+    lockup_client.set_position_token(&pool_id, &true);
+
+    lockup_client.invoke(
+        &pool_id,
+        &supply,
+        &supply_args,
+        &map![&e, (token_1_id.clone(), supply_amount)],
+    );
+    assert_eq!(lockup_client.deployed(&token_1_id), supply_amount);
+
+    // revoke with tokens deployed, and validate the balance is revoked immediately
+    e.jump_time_only(100); // t = 100
+    lockup_client.revoke(&tokens, &bombadil, &LeaverPolicy::Good);
+    let revoke_amount = token_1_total / 2;
+    let pending_amount = revoke_amount - (token_1_total - supply_amount);
+    assert_eq!(
+        token_1_client.balance(&bombadil),
+        token_1_total - supply_amount
+    );
+    assert_eq!(
+        lockup_client.revoked_amount(&token_1_id),
+        token_1_total - supply_amount
+    );
+    assert_eq!(
+        lockup_client.pending_revoke(&token_1_id),
+        Some(PendingRevoke {
+            to: bombadil.clone(),
+            amount: pending_amount,
+        })
+    );
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0]);
+
+    // validate tokens can't be deployed once the lockup is revoked
+    let result = lockup_client.try_invoke(
+        &pool_id,
+        &supply,
+        &supply_args,
+        &map![&e, (token_1_id.clone(), supply_amount)],
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(106))));
+
+    // the admin returns the deployed tokens, which settles the pending revoke
+    let withdraw_args: Vec<Val> = vec![&e, lockup_id.into_val(&e), supply_amount.into_val(&e)];
+    lockup_client.invoke(
+        &pool_id,
+        &withdraw,
+        &withdraw_args,
+        &map![&e, (token_1_id.clone(), 0)],
+    );
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_id.clone(),
+                    Symbol::new(&e, "invoke"),
+                    vec![
+                        &e,
+                        pool_id.into_val(&e),
+                        withdraw.into_val(&e),
+                        withdraw_args.into_val(&e),
+                        map![&e, (token_1_id.clone(), 0i128)].into_val(&e)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(lockup_client.deployed(&token_1_id), 0);
+    assert_eq!(lockup_client.pending_revoke(&token_1_id), None);
+    assert_eq!(token_1_client.balance(&bombadil), revoke_amount);
+    assert_eq!(lockup_client.revoked_amount(&token_1_id), revoke_amount);
+
+    // the owner claims the vested tokens
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, token_1_total - revoke_amount]
+    );
+    e.jump_time_only(100); // t = 200
+    lockup_client.claim(&tokens);
+    assert_eq!(
+        token_1_client.balance(&frodo),
+        token_1_total - revoke_amount
+    );
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
+
+#[test]
+fn test_lockup_invoke_revoke_renounced() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let pool_id = e.register_contract(None, MockPool {});
+    let pool_client = MockPoolClient::new(&e, &pool_id);
+    pool_client.set_token(&token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+    let supply = Symbol::new(&e, "supply");
+    let withdraw = Symbol::new(&e, "withdraw");
+    let supply_amount = token_1_total * 6 / 10;
+    lockup_client.set_allowed_call(&pool_id, &supply, &true);
+    lockup_client.set_allowed_call(&pool_id, &withdraw, &true);
+    lockup_client.set_position_token(&pool_id, &true);
+    lockup_client.invoke(
+        &pool_id,
+        &supply,
+        &vec![&e, lockup_id.into_val(&e), supply_amount.into_val(&e)],
+        &map![&e, (token_1_id.clone(), supply_amount)],
+    );
+
+    // revoke with tokens deployed, then renounce the admin
+    e.jump_time_only(100); // t = 100
+    lockup_client.revoke(&tokens, &bombadil, &LeaverPolicy::Good);
+    lockup_client.renounce_admin();
+    let revoke_amount = token_1_total / 2;
+
+    // validate the owner can return the deployed tokens, which settles the pending revoke
+    let withdraw_args: Vec<Val> = vec![&e, lockup_id.into_val(&e), supply_amount.into_val(&e)];
+    lockup_client.invoke(
+        &pool_id,
+        &withdraw,
+        &withdraw_args,
+        &map![&e, (token_1_id.clone(), 0i128)],
+    );
+    assert_eq!(
+        e.auths()[0],
+        (
+            frodo.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_id.clone(),
+                    Symbol::new(&e, "invoke"),
+                    vec![
+                        &e,
+                        pool_id.into_val(&e),
+                        withdraw.into_val(&e),
+                        withdraw_args.into_val(&e),
+                        map![&e, (token_1_id.clone(), 0i128)].into_val(&e)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(lockup_client.deployed(&token_1_id), 0);
+    assert_eq!(lockup_client.pending_revoke(&token_1_id), None);
+    assert_eq!(token_1_client.balance(&bombadil), revoke_amount);
+
+    // validate the owner still can't deploy tokens
+    let result = lockup_client.try_invoke(
+        &pool_id,
+        &supply,
+        &vec![&e, lockup_id.into_val(&e), supply_amount.into_val(&e)],
+        &map![&e, (token_1_id.clone(), supply_amount)],
+    );
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(106))));
+
+    lockup_client.claim(&tokens);
+    assert_eq!(
+        token_1_client.balance(&frodo),
+        token_1_total - revoke_amount
+    );
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
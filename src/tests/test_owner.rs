@@ -0,0 +1,218 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_set_owner() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+
+    // claim first unlock as the original owner
+    e.jump_time_only(150); // t = 150
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+
+    // reassign the owner
+    lockup_client.set_owner(&samwise);
+    let auth_invocation = AuthorizedInvocation {
+        function: AuthorizedFunction::Contract((
+            lockup_client.address.clone(),
+            Symbol::new(&e, "set_owner"),
+            vec![&e, samwise.into_val(&e)],
+        )),
+        sub_invocations: std::vec![],
+    };
+    assert_eq!(
+        e.auths(),
+        std::vec![
+            (frodo.clone(), auth_invocation.clone()),
+            (bombadil.clone(), auth_invocation)
+        ]
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_client.address.clone(),
+                (Symbol::new(&e, "set_owner"),).into_val(&e),
+                (frodo.clone(), samwise.clone()).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.owner(), samwise);
+
+    // validate the new owner receives the remaining tokens
+    e.jump_time_only(50); // t = 200
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "claim"),
+                    vec![&e, vec![&e, token_1_id.clone()].into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+}
+
+#[test]
+fn test_lockup_owner_recovery() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let gollum = Address::generate(&e);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+
+    let (_, lockup_client) = create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+    assert_eq!(lockup_client.pending_owner(), None);
+
+    // nothing to accept or cancel
+    let result = lockup_client.try_accept_owner();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(104))));
+    let result = lockup_client.try_cancel_owner_proposal();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(104))));
+
+    // admin proposes a new owner, and can cancel it
+    lockup_client.propose_owner(&gollum);
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "propose_owner"),
+                    vec![&e, gollum.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(lockup_client.pending_owner(), Some(gollum.clone()));
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_client.address.clone(),
+                (Symbol::new(&e, "propose_owner"),).into_val(&e),
+                gollum.into_val(&e)
+            )
+        ]
+    );
+    lockup_client.cancel_owner_proposal();
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_client.address.clone(),
+                (Symbol::new(&e, "cancel_owner_proposal"),).into_val(&e),
+                gollum.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.pending_owner(), None);
+
+    // admin proposes a new owner, which is accepted
+    lockup_client.propose_owner(&samwise);
+    assert_eq!(lockup_client.owner(), frodo);
+    lockup_client.accept_owner();
+    assert_eq!(
+        e.auths()[0],
+        (
+            samwise.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "accept_owner"),
+                    vec![&e]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_client.address.clone(),
+                (Symbol::new(&e, "accept_owner"),).into_val(&e),
+                (frodo.clone(), samwise.clone()).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.owner(), samwise);
+    assert_eq!(lockup_client.pending_owner(), None);
+
+    // set_owner clears any pending owner
+    lockup_client.propose_owner(&gollum);
+    lockup_client.set_owner(&frodo);
+    assert_eq!(lockup_client.owner(), frodo);
+    assert_eq!(lockup_client.pending_owner(), None);
+}
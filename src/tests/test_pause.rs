@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_pause() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+    assert!(!lockup_client.paused());
+
+    lockup_client.pause();
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "pause"),
+                    vec![&e]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "pause"),).into_val(&e),
+                e.ledger().timestamp().into_val(&e)
+            )
+        ]
+    );
+    assert!(lockup_client.paused());
+
+    // validate all claims fail while paused, but tokens continue to unlock
+    e.jump_time_only(150); // t = 150
+    let result = lockup_client.try_claim(&tokens);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(110))));
+    let result = lockup_client.try_claim_to(&tokens, &samwise, &None);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(110))));
+    let result = lockup_client.try_claim_amount(&token_1_id, &1);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(110))));
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, token_1_total / 2]
+    );
+
+    e.jump_time_only(50); // t = 200
+    lockup_client.unpause();
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "unpause"),).into_val(&e),
+                e.ledger().timestamp().into_val(&e)
+            )
+        ]
+    );
+    assert!(!lockup_client.paused());
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total);
+
+    // validate renouncing the admin unpauses claims
+    lockup_client.pause();
+    lockup_client.renounce_admin();
+    assert!(!lockup_client.paused());
+}
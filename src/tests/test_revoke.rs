@@ -0,0 +1,200 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{LeaverPolicy, Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_revoke_good_leaver() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_2_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_client = TokenClient::new(&e, &token_2_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Linear(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 0,
+        },
+        Unlock {
+            time: now + 1100,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    let token_2_total: i128 = 400_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    token_2_admin_client.mint(&lockup_id, &token_2_total);
+
+    e.jump_time_only(300); // t = 300
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 5);
+
+    // revoke token 1 halfway through the schedule
+    e.jump_time_only(300); // t = 600
+    lockup_client.revoke(
+        &vec![&e, token_1_id.clone()],
+        &bombadil,
+        &LeaverPolicy::Good,
+    );
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "revoke"),
+                    vec![
+                        &e,
+                        vec![&e, token_1_id.clone()].into_val(&e),
+                        bombadil.into_val(&e),
+                        LeaverPolicy::Good.into_val(&e)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (
+                    Symbol::new(&e, "revoke"),
+                    token_1_id.clone(),
+                    bombadil.clone()
+                )
+                    .into_val(&e),
+                (token_1_total / 2, LeaverPolicy::Good).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.revoked(), Some(e.ledger().timestamp()));
+    assert_eq!(lockup_client.revoked_amount(&token_1_id), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&bombadil), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&lockup_id), 3 * token_1_total / 10);
+
+    // validate no further vesting occurs, and the vested tokens remain claimable
+    e.jump_time_only(500); // t = 1100
+    let tokens = vec![&e, token_1_id.clone(), token_2_id.clone()];
+    assert_eq!(
+        lockup_client.claimable(&tokens),
+        vec![&e, 3 * token_1_total / 10, token_2_total / 2]
+    );
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_2_client.balance(&frodo), token_2_total / 2);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+    assert_eq!(token_2_client.balance(&lockup_id), token_2_total / 2);
+
+    // revoke the remaining token 2 later
+    lockup_client.revoke(
+        &vec![&e, token_2_id.clone()],
+        &bombadil,
+        &LeaverPolicy::Good,
+    );
+    assert_eq!(token_2_client.balance(&bombadil), token_2_total / 2);
+    assert_eq!(token_2_client.balance(&lockup_id), 0);
+    assert_eq!(lockup_client.revoked_amount(&token_2_id), token_2_total / 2);
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0, 0]);
+
+    // validate the unlocks can no longer be changed
+    let result = lockup_client.try_set_unlocks(&unlocks, &None);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(106))));
+}
+
+#[test]
+fn test_lockup_revoke_bad_leaver() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_2_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_client = TokenClient::new(&e, &token_2_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    let token_2_total: i128 = 400_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&bombadil, &token_1_total);
+    lockup_client.deposit(&bombadil, &token_1_id, &token_1_total);
+    token_2_admin_client.mint(&lockup_id, &token_2_total);
+    let tokens = vec![&e, token_1_id.clone(), token_2_id.clone()];
+
+    // claim the first unlock of token 1 only
+    e.jump_time_only(150); // t = 150
+    lockup_client.claim(&vec![&e, token_1_id.clone()]);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+
+    // validate the vested but unclaimed tokens are forfeited
+    lockup_client.revoke(&tokens, &samwise, &LeaverPolicy::Bad);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total / 2);
+    assert_eq!(token_2_client.balance(&samwise), token_2_total);
+    assert_eq!(token_1_client.balance(&lockup_id), 0);
+    assert_eq!(token_2_client.balance(&lockup_id), 0);
+
+    // validate the deposited tokens reconcile with the claimed and revoked tokens
+    assert_eq!(lockup_client.revoked_amount(&token_1_id), token_1_total / 2);
+    assert_eq!(lockup_client.revoked_amount(&token_2_id), token_2_total);
+    assert_eq!(
+        lockup_client.deposited(&token_1_id)
+            - lockup_client.claimed(&token_1_id)
+            - lockup_client.revoked_amount(&token_1_id),
+        token_1_client.balance(&lockup_id)
+    );
+
+    e.jump_time_only(50); // t = 200
+    assert_eq!(lockup_client.claimable(&tokens), vec![&e, 0, 0]);
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_2_client.balance(&frodo), 0);
+}
@@ -1,14 +1,15 @@
 #![cfg(test)]
 
 use soroban_sdk::{
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
     token::{StellarAssetClient, TokenClient},
-    vec, Address, Env, IntoVal, Symbol, Vec,
+    vec, Address, Env, IntoVal, Symbol,
 };
 
 use crate::{
     testutils::{create_token_lockup_wasm, EnvTestUtils},
-    types::Unlock,
+    types::{Schedule, Unlock},
 };
 
 #[test]
@@ -25,7 +26,7 @@ fn test_lockup_claim() {
     let token_1_client = TokenClient::new(&e, &token_1_id);
 
     let now = e.ledger().timestamp();
-    let unlocks: Vec<Unlock> = vec![
+    let unlocks = Schedule::Step(vec![
         &e,
         Unlock {
             time: now + 100,
@@ -35,9 +36,10 @@ fn test_lockup_claim() {
             time: now + 200,
             percent: 10000,
         },
-    ];
+    ]);
 
-    let (lockup_id, lockup_client) = create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks);
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
 
     // send tokens to lockup
     let token_1_total: i128 = 3_000 * 10i128.pow(7);
@@ -51,7 +53,7 @@ fn test_lockup_claim() {
     assert_eq!(token_1_client.balance(&lockup_id), token_1_total / 2);
 
     // verify set_unlocks validates unlocks
-    let invalid_unlocks: Vec<Unlock> = vec![
+    let invalid_unlocks = Schedule::Step(vec![
         &e,
         Unlock {
             time: now + 100,
@@ -65,15 +67,15 @@ fn test_lockup_claim() {
             time: now + 300,
             percent: 10000,
         },
-    ];
-    let result = lockup_client.try_set_unlocks(&invalid_unlocks);
+    ]);
+    let result = lockup_client.try_set_unlocks(&invalid_unlocks, &None);
     assert_eq!(
         result.err(),
         Some(Ok(soroban_sdk::Error::from_contract_error(102)))
     );
 
     // set valid unlocks
-    let valid_unlocks: Vec<Unlock> = vec![
+    let valid_unlocks = Schedule::Step(vec![
         &e,
         Unlock {
             time: now + 100,
@@ -87,8 +89,22 @@ fn test_lockup_claim() {
             time: now + 300,
             percent: 10000,
         },
-    ];
-    lockup_client.set_unlocks(&valid_unlocks);
+    ]);
+    lockup_client.set_unlocks(&valid_unlocks, &None);
+
+    // validate set_unlocks event
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "set_unlocks"),).into_val(&e),
+                (unlocks.clone(), valid_unlocks.clone()).into_val(&e)
+            )
+        ]
+    );
 
     // validate admin is authenticated
     assert_eq!(
@@ -99,24 +115,13 @@ fn test_lockup_claim() {
                 function: AuthorizedFunction::Contract((
                     lockup_client.address.clone(),
                     Symbol::new(&e, "set_unlocks"),
-                    vec![&e, valid_unlocks.into_val(&e),]
+                    vec![&e, valid_unlocks.into_val(&e), None::<Address>.into_val(&e)]
                 )),
                 sub_invocations: std::vec![]
             }
         )
     );
-    let unlocks = lockup_client.unlocks();
-    assert_eq!(unlocks.len(), valid_unlocks.len());
-    for i in 0..unlocks.len() {
-        assert_eq!(
-            unlocks.get_unchecked(i).time,
-            valid_unlocks.get_unchecked(i).time
-        );
-        assert_eq!(
-            unlocks.get_unchecked(i).percent,
-            valid_unlocks.get_unchecked(i).percent
-        );
-    }
+    assert_eq!(lockup_client.unlocks(), valid_unlocks);
 
     // claim second unlock
     e.jump_time_only(100); // t = 250
@@ -0,0 +1,147 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_token_unlocks() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_2_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_3_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_2_admin_client = StellarAssetClient::new(&e, &token_2_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+    let token_2_client = TokenClient::new(&e, &token_2_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+    let token_2_unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 300,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 600,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) = create_token_lockup_wasm(
+        &e,
+        &bombadil,
+        &frodo,
+        &unlocks,
+        &map![&e, (token_2_id.clone(), token_2_unlocks.clone())],
+    );
+
+    // validate the token unlocks are emitted on initialize
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "set_token_unlocks"), token_2_id.clone()).into_val(&e),
+                (None::<Schedule>, token_2_unlocks.clone()).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.token_unlocks(&token_1_id), None);
+    assert_eq!(
+        lockup_client.token_unlocks(&token_2_id),
+        Some(token_2_unlocks.clone())
+    );
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    let token_2_total: i128 = 400_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&lockup_id, &token_1_total);
+    token_2_admin_client.mint(&lockup_id, &token_2_total);
+    let tokens = vec![&e, token_1_id.clone(), token_2_id.clone()];
+
+    // validate each token uses its own schedule
+    e.jump_time_only(150); // t = 150
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_2_client.balance(&frodo), 0);
+
+    // change the unlocks of token 2
+    let new_token_2_unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 300,
+            percent: 2500,
+        },
+        Unlock {
+            time: now + 600,
+            percent: 10000,
+        },
+    ]);
+    lockup_client.set_unlocks(&new_token_2_unlocks, &Some(token_2_id.clone()));
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "set_token_unlocks"), token_2_id.clone()).into_val(&e),
+                (Some(token_2_unlocks), new_token_2_unlocks.clone()).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(
+        lockup_client.token_unlocks(&token_2_id),
+        Some(new_token_2_unlocks)
+    );
+    assert_eq!(lockup_client.unlocks(), unlocks);
+
+    // validate new token unlocks can't change the lockup unlocks that already occured
+    let token_3_unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 300,
+            percent: 10000,
+        },
+    ]);
+    let result = lockup_client.try_set_unlocks(&token_3_unlocks, &Some(token_3_id.clone()));
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(102))));
+    assert_eq!(lockup_client.token_unlocks(&token_3_id), None);
+
+    e.jump_time_only(150); // t = 300
+    lockup_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total);
+    assert_eq!(token_2_client.balance(&frodo), token_2_total / 4);
+
+    e.jump_time_only(300); // t = 600
+    lockup_client.claim(&tokens);
+    assert_eq!(token_2_client.balance(&frodo), token_2_total);
+    assert_eq!(token_2_client.balance(&lockup_id), 0);
+}
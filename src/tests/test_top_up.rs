@@ -0,0 +1,238 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{create_token_lockup_wasm, EnvTestUtils},
+    types::{Schedule, TopUpPolicy, Unlock},
+};
+
+#[test]
+fn test_lockup_top_up_step() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (future_id, future_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+    let (retro_id, retro_client) =
+        create_token_lockup_wasm(&e, &bombadil, &samwise, &unlocks, &map![&e]);
+    assert_eq!(future_client.top_up_policy(), TopUpPolicy::Future);
+
+    retro_client.set_top_up_policy(&TopUpPolicy::Retroactive);
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    retro_client.address.clone(),
+                    Symbol::new(&e, "set_top_up_policy"),
+                    vec![&e, TopUpPolicy::Retroactive.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                retro_id.clone(),
+                (Symbol::new(&e, "set_top_up_policy"),).into_val(&e),
+                TopUpPolicy::Retroactive.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(retro_client.top_up_policy(), TopUpPolicy::Retroactive);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&future_id, &token_1_total);
+    token_1_admin_client.mint(&retro_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    e.jump_time_only(150); // t = 150
+    future_client.claim(&tokens);
+    retro_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total / 2);
+
+    // top up both lockups between unlocks
+    e.jump_time_only(10); // t = 160
+    token_1_admin_client.mint(&future_id, &token_1_total);
+    token_1_admin_client.mint(&retro_id, &token_1_total);
+
+    // validate the retroactive top-up vests on the unlock that already occured
+    assert_eq!(future_client.claimable(&tokens), vec![&e, 0]);
+    assert_eq!(retro_client.claimable(&tokens), vec![&e, token_1_total / 2]);
+    future_client.claim(&tokens);
+    retro_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total);
+
+    e.jump_time_only(40); // t = 200
+    future_client.claim(&tokens);
+    retro_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 2 * token_1_total);
+    assert_eq!(token_1_client.balance(&samwise), 2 * token_1_total);
+}
+
+#[test]
+fn test_lockup_top_up_linear() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Linear(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 0,
+        },
+        Unlock {
+            time: now + 300,
+            percent: 10000,
+        },
+    ]);
+
+    let (future_id, future_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+    let (retro_id, retro_client) =
+        create_token_lockup_wasm(&e, &bombadil, &samwise, &unlocks, &map![&e]);
+    retro_client.set_top_up_policy(&TopUpPolicy::Retroactive);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&future_id, &token_1_total);
+    token_1_admin_client.mint(&retro_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    // claim halfway through the schedule, then top up
+    e.jump_time_only(200); // t = 200
+    future_client.claim(&tokens);
+    retro_client.claim(&tokens);
+    token_1_admin_client.mint(&future_id, &token_1_total);
+    token_1_admin_client.mint(&retro_id, &token_1_total);
+
+    // validate the future top-up accrues over the remaining time, while half of the
+    // retroactive top-up has already accrued
+    e.jump_time_only(50); // t = 250
+    assert_eq!(
+        future_client.claimable(&tokens),
+        vec![&e, 3 * token_1_total / 4]
+    );
+    assert_eq!(retro_client.claimable(&tokens), vec![&e, token_1_total]);
+
+    e.jump_time_only(50); // t = 300
+    future_client.claim(&tokens);
+    retro_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 2 * token_1_total);
+    assert_eq!(token_1_client.balance(&samwise), 2 * token_1_total);
+}
+
+#[test]
+fn test_lockup_top_up_after_unclaimed_unlock() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let token_1_id = e.register_stellar_asset_contract(bombadil.clone());
+    let token_1_admin_client = StellarAssetClient::new(&e, &token_1_id);
+    let token_1_client = TokenClient::new(&e, &token_1_id);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 5000,
+        },
+        Unlock {
+            time: now + 200,
+            percent: 10000,
+        },
+    ]);
+
+    let (future_id, future_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+    let (retro_id, retro_client) =
+        create_token_lockup_wasm(&e, &bombadil, &samwise, &unlocks, &map![&e]);
+    retro_client.set_top_up_policy(&TopUpPolicy::Retroactive);
+
+    let token_1_total: i128 = 1_000 * 10i128.pow(7);
+    token_1_admin_client.mint(&future_id, &token_1_total);
+    token_1_admin_client.mint(&retro_id, &token_1_total);
+    let tokens = vec![&e, token_1_id.clone()];
+
+    // claim before the first unlock
+    e.jump_time_only(50); // t = 50
+    future_client.claim(&tokens);
+    retro_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 0);
+    assert_eq!(token_1_client.balance(&samwise), 0);
+
+    // top up both lockups after the first unlock, before it is claimed
+    e.jump_time_only(100); // t = 150
+    token_1_admin_client.mint(&bombadil, &(2 * token_1_total));
+    future_client.deposit(&bombadil, &token_1_id, &token_1_total);
+    retro_client.deposit(&bombadil, &token_1_id, &token_1_total);
+
+    // validate the future top-up does not vest on the unlock that occured before the deposit
+    assert_eq!(
+        future_client.claimable(&tokens),
+        vec![&e, token_1_total / 2]
+    );
+    assert_eq!(retro_client.claimable(&tokens), vec![&e, token_1_total]);
+    future_client.claim(&tokens);
+    retro_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), token_1_total / 2);
+    assert_eq!(token_1_client.balance(&samwise), token_1_total);
+
+    e.jump_time_only(50); // t = 200
+    future_client.claim(&tokens);
+    retro_client.claim(&tokens);
+    assert_eq!(token_1_client.balance(&frodo), 2 * token_1_total);
+    assert_eq!(token_1_client.balance(&samwise), 2 * token_1_total);
+    assert_eq!(token_1_client.balance(&future_id), 0);
+    assert_eq!(token_1_client.balance(&retro_id), 0);
+}
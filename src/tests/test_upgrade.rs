@@ -0,0 +1,185 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
+    vec, Address, Env, Error, IntoVal, Symbol,
+};
+
+use crate::{
+    testutils::{contract, create_token_lockup_wasm, EnvTestUtils},
+    types::{PendingUpgrade, Schedule, Unlock},
+};
+
+#[test]
+fn test_lockup_upgrade() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+
+    let (lockup_id, lockup_client) =
+        create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+    let wasm_hash = e.deployer().upload_contract_wasm(contract::WASM);
+    assert_eq!(lockup_client.upgrade_delay(), 7 * 24 * 60 * 60);
+    assert_eq!(lockup_client.pending_upgrade(), None);
+
+    // nothing to upgrade or cancel
+    let result = lockup_client.try_upgrade();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(107))));
+    let result = lockup_client.try_cancel_upgrade();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(107))));
+
+    // validate the upgrade delay can only be increased
+    let result = lockup_client.try_set_upgrade_delay(&0);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(109))));
+    lockup_client.set_upgrade_delay(&(14 * 24 * 60 * 60));
+    assert_eq!(lockup_client.upgrade_delay(), 14 * 24 * 60 * 60);
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "set_upgrade_delay"),).into_val(&e),
+                (14u64 * 24 * 60 * 60).into_val(&e)
+            )
+        ]
+    );
+
+    // propose an upgrade
+    lockup_client.propose_upgrade(&wasm_hash);
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "propose_upgrade"),
+                    vec![&e, wasm_hash.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let executable_at = e.ledger().timestamp() + 14 * 24 * 60 * 60;
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "propose_upgrade"),).into_val(&e),
+                (wasm_hash.clone(), executable_at).into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(
+        lockup_client.pending_upgrade(),
+        Some(PendingUpgrade {
+            wasm_hash: wasm_hash.clone(),
+            executable_at
+        })
+    );
+
+    // validate increasing the delay also delays the pending upgrade
+    lockup_client.set_upgrade_delay(&(21 * 24 * 60 * 60));
+    assert_eq!(
+        lockup_client.pending_upgrade(),
+        Some(PendingUpgrade {
+            wasm_hash: wasm_hash.clone(),
+            executable_at: executable_at + 7 * 24 * 60 * 60
+        })
+    );
+
+    // validate the upgrade can't be executed before the delay
+    e.jump_time_only(21 * 24 * 60 * 60 - 1);
+    let result = lockup_client.try_upgrade();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(108))));
+
+    // execute the upgrade
+    e.jump_time_only(1);
+    lockup_client.upgrade();
+    assert_eq!(
+        e.auths()[0],
+        (
+            bombadil.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    lockup_client.address.clone(),
+                    Symbol::new(&e, "upgrade"),
+                    vec![&e]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    let events = e.events().all();
+    assert_eq!(
+        vec![&e, events.last_unchecked()],
+        vec![
+            &e,
+            (
+                lockup_id.clone(),
+                (Symbol::new(&e, "upgrade"),).into_val(&e),
+                wasm_hash.into_val(&e)
+            )
+        ]
+    );
+    assert_eq!(lockup_client.pending_upgrade(), None);
+    assert_eq!(lockup_client.owner(), frodo);
+}
+
+#[test]
+fn test_lockup_upgrade_renounced() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+    e.set_default_info();
+    e.mock_all_auths();
+
+    let bombadil = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    let now = e.ledger().timestamp();
+    let unlocks = Schedule::Step(vec![
+        &e,
+        Unlock {
+            time: now + 100,
+            percent: 10000,
+        },
+    ]);
+
+    let (_, lockup_client) = create_token_lockup_wasm(&e, &bombadil, &frodo, &unlocks, &map![&e]);
+    let wasm_hash = e.deployer().upload_contract_wasm(contract::WASM);
+
+    // validate the admin can cancel a pending upgrade
+    lockup_client.propose_upgrade(&wasm_hash);
+    lockup_client.cancel_upgrade();
+    assert_eq!(lockup_client.pending_upgrade(), None);
+
+    // validate renouncing the admin clears any pending upgrade and prevents upgrades
+    lockup_client.propose_upgrade(&wasm_hash);
+    lockup_client.renounce_admin();
+    assert_eq!(lockup_client.pending_upgrade(), None);
+
+    e.jump_time_only(7 * 24 * 60 * 60);
+    let result = lockup_client.try_propose_upgrade(&wasm_hash);
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+    let result = lockup_client.try_upgrade();
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(105))));
+}
@@ -1,32 +1,68 @@
 #![cfg(test)]
 
-use crate::{contract::TokenLockupClient, types::Unlock};
+use crate::{
+    contract::{TokenLockup, TokenLockupClient},
+    types::Schedule,
+};
 use soroban_sdk::{
-    testutils::{Ledger as _, LedgerInfo},
-    Address, Env, Vec,
+    testutils::{BytesN as _, Ledger as _, LedgerInfo},
+    Address, BytesN, Env, Map,
 };
-mod contract {
+#[allow(clippy::too_many_arguments)]
+pub mod contract {
     soroban_sdk::contractimport!(
         file = "./target/wasm32-unknown-unknown/optimized/token_lockup.wasm"
     );
 }
 
-/// Create a blend lockup contract via wasm
+/// Register an uninitialized lockup contract at the address derived from the deployer
+/// and a random salt
+///
+/// Returns the salt and the address of the lockup
+///
+/// ### Arguments
+/// * `deployer` - The address of the deployer
+pub fn register_token_lockup(e: &Env, deployer: &Address) -> (BytesN<32>, Address) {
+    let salt = BytesN::<32>::random(e);
+    let token_lockup_address = e
+        .deployer()
+        .with_address(deployer.clone(), salt.clone())
+        .deployed_address();
+    e.register_contract(&token_lockup_address, TokenLockup {});
+    (salt, token_lockup_address)
+}
+
+/// Create a blend lockup contract via wasm, deployed by the admin
 ///
 /// ### Arguments
 /// * `admin` - The address of the admin
 /// * `owner` - The address of the owner
-/// * `unlocks` - The unlock ledger time (in seconds)
+/// * `unlocks` - The unlock schedule
+/// * `token_unlocks` - The unlock schedules of individual tokens
 pub fn create_token_lockup_wasm<'a>(
     e: &Env,
     admin: &Address,
     owner: &Address,
-    unlocks: &Vec<Unlock>,
+    unlocks: &Schedule,
+    token_unlocks: &Map<Address, Schedule>,
 ) -> (Address, TokenLockupClient<'a>) {
-    let token_lockup_address = e.register_contract_wasm(None, contract::WASM);
+    let salt = BytesN::<32>::random(e);
+    let token_lockup_address = e
+        .deployer()
+        .with_address(admin.clone(), salt.clone())
+        .deployed_address();
+    e.register_contract_wasm(&token_lockup_address, contract::WASM);
     let token_lockup_client: TokenLockupClient<'a> =
-        TokenLockupClient::new(&e, &token_lockup_address);
-    token_lockup_client.initialize(admin, owner, unlocks);
+        TokenLockupClient::new(e, &token_lockup_address);
+    token_lockup_client.initialize(
+        admin,
+        &salt,
+        admin,
+        owner,
+        unlocks,
+        token_unlocks,
+        &Map::new(e),
+    );
     (token_lockup_address, token_lockup_client)
 }
 
@@ -56,7 +92,7 @@ impl EnvTestUtils for Env {
             sequence_number: self.ledger().sequence().saturating_add(ledgers),
             network_id: Default::default(),
             base_reserve: 10,
-            min_temp_entry_ttl: 1 * ONE_DAY_LEDGERS,
+            min_temp_entry_ttl: ONE_DAY_LEDGERS,
             min_persistent_entry_ttl: 120 * ONE_DAY_LEDGERS,
             max_entry_ttl: 365 * ONE_DAY_LEDGERS,
         });
@@ -69,7 +105,7 @@ impl EnvTestUtils for Env {
             sequence_number: self.ledger().sequence(),
             network_id: Default::default(),
             base_reserve: 10,
-            min_temp_entry_ttl: 1 * ONE_DAY_LEDGERS,
+            min_temp_entry_ttl: ONE_DAY_LEDGERS,
             min_persistent_entry_ttl: 120 * ONE_DAY_LEDGERS,
             max_entry_ttl: 365 * ONE_DAY_LEDGERS,
         });
@@ -82,7 +118,7 @@ impl EnvTestUtils for Env {
             sequence_number: 100,
             network_id: Default::default(),
             base_reserve: 10,
-            min_temp_entry_ttl: 1 * ONE_DAY_LEDGERS,
+            min_temp_entry_ttl: ONE_DAY_LEDGERS,
             min_persistent_entry_ttl: 120 * ONE_DAY_LEDGERS,
             max_entry_ttl: 365 * ONE_DAY_LEDGERS,
         });
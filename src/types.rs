@@ -1,10 +1,167 @@
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
 
 #[contracttype]
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Unlock {
     /// The ledger time (in seconds) the unlock occurs
     pub time: u64,
     /// The amount of current tokens (in bps) to unlock
     pub percent: u32,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AmountUnlock {
+    /// The ledger time (in seconds) the unlock occurs
+    pub time: u64,
+    /// The amount of tokens to unlock
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CliffLinear {
+    /// The ledger time (in seconds) tokens start accruing
+    pub start: u64,
+    /// The ledger time (in seconds) of the cliff. No tokens can be claimed before the cliff.
+    pub cliff: u64,
+    /// The ledger time (in seconds) all tokens are unlocked
+    pub end: u64,
+    /// The amount of tokens (in bps) unlocked at the cliff, in addition to the tokens that
+    /// accrued between the start and the cliff
+    pub cliff_percent: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Periodic {
+    /// The ledger time (in seconds) the first period starts
+    pub start: u64,
+    /// The length (in seconds) of each period
+    pub period: u64,
+    /// The number of periods. An equal portion of the tokens unlocks at the end of each period.
+    pub periods: u32,
+    /// The number of periods before the cliff. The portion of any periods that end before the
+    /// cliff unlocks at the cliff. Use 0 for no cliff.
+    pub cliff_periods: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingUpgrade {
+    /// The hash of the wasm the lockup will be upgraded to
+    pub wasm_hash: BytesN<32>,
+    /// The ledger time (in seconds) the upgrade can be executed
+    pub executable_at: u64,
+}
+
+/// Revoked tokens that were deployed to other contracts at the time of revocation
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingRevoke {
+    /// The address the revoked tokens are transferred to
+    pub to: Address,
+    /// The amount of revoked tokens that are deployed, and are transferred once they are returned
+    pub amount: i128,
+}
+
+/// How vested tokens are treated when the lockup is revoked
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LeaverPolicy {
+    /// Tokens vested at the time of revocation remain claimable by the owner
+    Good,
+    /// Tokens vested at the time of revocation that have not been claimed are forfeited, even if
+    /// the cliff has passed. Tokens the owner has already claimed are not affected.
+    Bad,
+}
+
+/// How tokens deposited after the first claim of a token vest. Only applies to step, linear,
+/// cliff linear, and periodic schedules, including ledger schedules. Tokens transferred directly to
+/// the lockup, rather than with `deposit`, are treated as deposited at the last claim or deposit of the token.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TopUpPolicy {
+    /// Top-ups only vest on unlocks that occur after the deposit
+    Future,
+    /// Top-ups vest on the original schedule, including any unlocks that have already occured
+    Retroactive,
+}
+
+/// The schedule tokens are unlocked on
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Schedule {
+    /// The percent of each unlock can be claimed once the unlock time is reached
+    Step(Vec<Unlock>),
+    /// The percent of each unlock accrues linearly between the previous unlock time and the
+    /// unlock time. The first unlock can be claimed once its unlock time is reached, and can
+    /// have a percent of 0 to start accruing at that time.
+    Linear(Vec<Unlock>),
+    /// The cliff percent can be claimed at the cliff, and the remaining tokens accrue linearly
+    /// from the start to the end. Any tokens accrued before the cliff can be claimed at the cliff.
+    CliffLinear(CliffLinear),
+    /// The amount of each unlock can be claimed once the unlock time is reached, regardless of
+    /// the lockup's balance. Any balance above the sum of the amounts can be claimed after the
+    /// final unlock. Can only be used as the schedule of a token.
+    Amount(Vec<AmountUnlock>),
+    /// The percent of each unlock is the cumulative percent of all tokens ever deposited that can be
+    /// claimed once the unlock time is reached. Percents must be non-decreasing.
+    Cumulative(Vec<Unlock>),
+    /// A step schedule where each unlock time is a ledger sequence number
+    LedgerStep(Vec<Unlock>),
+    /// A linear schedule where each unlock time is a ledger sequence number
+    LedgerLinear(Vec<Unlock>),
+    /// An equal portion of the tokens can be claimed at the end of each period. Unlocks are
+    /// calculated from the period, so any number of periods can be used.
+    Periodic(Periodic),
+}
+
+impl Schedule {
+    /// Check if the unlock times of the schedule are ledger sequence numbers
+    pub fn is_ledger(&self) -> bool {
+        matches!(self, Schedule::LedgerStep(_) | Schedule::LedgerLinear(_))
+    }
+
+    /// Get the current time of the schedule, which is the ledger sequence number for ledger
+    /// schedules, and the ledger time (in seconds) otherwise
+    pub fn now(&self, e: &Env) -> u64 {
+        if self.is_ledger() {
+            e.ledger().sequence() as u64
+        } else {
+            e.ledger().timestamp()
+        }
+    }
+
+    /// Get the time the first tokens can be claimed, in the units of the schedule's unlock times
+    pub fn start_time(&self) -> u64 {
+        match self {
+            Schedule::Step(unlocks)
+            | Schedule::Linear(unlocks)
+            | Schedule::Cumulative(unlocks)
+            | Schedule::LedgerStep(unlocks)
+            | Schedule::LedgerLinear(unlocks) => unlocks.first_unchecked().time,
+            Schedule::CliffLinear(cliff_linear) => cliff_linear.cliff,
+            Schedule::Periodic(periodic) => {
+                periodic.start + periodic.period * periodic.cliff_periods.max(1) as u64
+            }
+            Schedule::Amount(unlocks) => unlocks.first_unchecked().time,
+        }
+    }
+
+    /// Get the time all tokens can be claimed, in the units of the schedule's unlock times
+    pub fn end_time(&self) -> u64 {
+        match self {
+            Schedule::Step(unlocks)
+            | Schedule::Linear(unlocks)
+            | Schedule::Cumulative(unlocks)
+            | Schedule::LedgerStep(unlocks)
+            | Schedule::LedgerLinear(unlocks) => unlocks.last_unchecked().time,
+            Schedule::CliffLinear(cliff_linear) => cliff_linear.end,
+            Schedule::Periodic(periodic) => {
+                periodic.start + periodic.period * periodic.periods as u64
+            }
+            Schedule::Amount(unlocks) => unlocks.last_unchecked().time,
+        }
+    }
+}
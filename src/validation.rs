@@ -1,34 +1,230 @@
-use soroban_sdk::{panic_with_error, Env, Vec};
+use core::mem::discriminant;
 
-use crate::{errors::TokenLockupError, storage, types::Unlock};
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Map, Vec};
 
-/// Validate the unlock times and unlock percents. If a current unlocks are already set, validates that
-/// any unlocks that have already occured remain unchanged. A maximum of 48 unlock periods are supported.
+use crate::{
+    errors::TokenLockupError,
+    storage,
+    types::{AmountUnlock, Schedule, Unlock},
+};
+
+/// Get the admin address
 ///
-/// Panic if the unlock times are not in ascending order, if the unlock percents are not valid, or if
-/// there are more than 48 unlock periods.
-pub fn require_valid_unlocks(e: &Env, unlocks: &Vec<Unlock>) {
-    if unlocks.is_empty() || unlocks.len() > 48 || unlocks.last_unchecked().percent != 10000 {
-        panic_with_error!(&e, TokenLockupError::InvalidUnlocks);
+/// Panic if the admin role has been renounced
+pub fn require_admin(e: &Env) -> Address {
+    match storage::get_admin(e) {
+        Some(admin) => admin,
+        None => panic_with_error!(e, TokenLockupError::AdminRenounced),
     }
+}
 
-    let prev_unlocks_opt = storage::get_unlocks(e);
-    if let Some(ref prev_unlocks) = prev_unlocks_opt {
+/// Require that the lockup was deployed by the deployer with the salt, and that the deployer
+/// authorized the call
+///
+/// Panics if the lockup's address was not derived from the deployer and salt
+pub fn require_deployer(e: &Env, deployer: &Address, salt: &BytesN<32>) {
+    deployer.require_auth();
+    let deployed_address = e
+        .deployer()
+        .with_address(deployer.clone(), salt.clone())
+        .deployed_address();
+    if deployed_address != e.current_contract_address() {
+        panic_with_error!(e, TokenLockupError::UnauthorizedError);
+    }
+}
+
+/// Require that claims are not paused
+///
+/// Panics if claims are paused
+pub fn require_not_paused(e: &Env) {
+    if storage::get_paused(e) {
+        panic_with_error!(e, TokenLockupError::ClaimsPaused);
+    }
+}
+
+/// Require that a token is not a position token
+///
+/// Panics if the token is a position token
+pub fn require_not_position_token(e: &Env, token: &Address) {
+    if storage::get_position_token(e, token) {
+        panic_with_error!(e, TokenLockupError::PositionToken);
+    }
+}
+
+/// Require that the lockup has a single owner
+///
+/// Panics if the lockup has beneficiaries
+pub fn require_no_beneficiaries(e: &Env) {
+    if !storage::get_beneficiaries(e).is_empty() {
+        panic_with_error!(e, TokenLockupError::BeneficiaryLockup);
+    }
+}
+
+/// Require that a schedule can be used as the lockup's schedule. Amount schedules unlock a fixed
+/// amount of a single token, so they can only be used as the schedule of a token.
+///
+/// Panics if the schedule is an amount schedule
+pub fn require_lockup_schedule(e: &Env, schedule: &Schedule) {
+    if let Schedule::Amount(_) = schedule {
+        panic_with_error!(e, TokenLockupError::InvalidUnlocks);
+    }
+}
+
+/// Validate the shares of the beneficiaries. An empty map is valid, and represents a lockup
+/// with a single owner.
+///
+/// Panics if any share is zero, or if the shares do not sum to 10000
+pub fn require_valid_shares(e: &Env, beneficiaries: &Map<Address, u32>) {
+    if beneficiaries.is_empty() {
+        return;
+    }
+
+    let mut total: u64 = 0;
+    for (_, share) in beneficiaries.iter() {
+        if share == 0 {
+            panic_with_error!(e, TokenLockupError::InvalidShares);
+        }
+        total += share as u64;
+    }
+    if total != 10000 {
+        panic_with_error!(e, TokenLockupError::InvalidShares);
+    }
+}
+
+/// Validate the unlock times and unlock percents. If a previous schedule is provided, validates that
+/// any unlocks that have already occured remain unchanged. A maximum of 48 unlock periods are supported.
+///
+/// For linear schedules, the unlock currently accruing must also remain unchanged, and the first unlock
+/// can have a percent of 0. Cliff linear schedules cannot be changed once the cliff has occured. For
+/// amount schedules, the unlock amounts must be positive. Periodic schedules are not limited
+/// to 48 periods, and cannot be changed once the first period has unlocked. For cumulative schedules, the unlock
+/// percents must be non-decreasing. The schedule type cannot be changed once
+/// the first unlock has occured. Ledger schedules are validated against the ledger sequence number,
+/// and cannot replace or be replaced by other schedules.
+///
+/// Panic if the unlock times are not in ascending order, if the unlock percents or amounts are not valid,
+/// or if there are more than 48 unlock periods.
+pub fn require_valid_unlocks(e: &Env, schedule: &Schedule, prev_schedule_opt: &Option<Schedule>) {
+    let now = schedule.now(e);
+    if let Some(prev_schedule) = prev_schedule_opt {
+        // unlock times of ledger schedules can't be compared to other schedules
+        if prev_schedule.is_ledger() != schedule.is_ledger() {
+            panic_with_error!(&e, TokenLockupError::InvalidUnlocks);
+        }
         // check if prev_unlocks are already unlocked
-        if prev_unlocks.last_unchecked().time <= e.ledger().timestamp() {
+        if prev_schedule.end_time() <= now {
+            panic_with_error!(&e, TokenLockupError::AlreadyUnlocked);
+        }
+        // check if the schedule type is changed after the first unlock
+        if discriminant(prev_schedule) != discriminant(schedule)
+            && prev_schedule.start_time() <= now
+        {
             panic_with_error!(&e, TokenLockupError::AlreadyUnlocked);
         }
     }
+
+    match schedule {
+        Schedule::Step(unlocks)
+        | Schedule::Linear(unlocks)
+        | Schedule::LedgerStep(unlocks)
+        | Schedule::LedgerLinear(unlocks) => {
+            let is_linear = matches!(schedule, Schedule::Linear(_) | Schedule::LedgerLinear(_));
+            let prev_unlocks = get_prev_unlocks(prev_schedule_opt);
+            require_valid_unlock_vec(e, unlocks, is_linear, &prev_unlocks, now);
+        }
+        Schedule::Cumulative(unlocks) => {
+            let prev_unlocks = get_prev_unlocks(prev_schedule_opt);
+            require_valid_unlock_vec(e, unlocks, false, &prev_unlocks, now);
+
+            let mut last_percent = 0;
+            for unlock in unlocks.iter() {
+                if unlock.percent < last_percent {
+                    panic_with_error!(&e, TokenLockupError::InvalidUnlocks);
+                }
+                last_percent = unlock.percent;
+            }
+        }
+        Schedule::CliffLinear(cliff_linear) => {
+            if cliff_linear.start > cliff_linear.cliff
+                || cliff_linear.cliff >= cliff_linear.end
+                || cliff_linear.cliff_percent > 10000
+            {
+                panic_with_error!(&e, TokenLockupError::InvalidUnlocks);
+            }
+
+            if let Some(prev_schedule) = prev_schedule_opt {
+                if prev_schedule.start_time() <= now && prev_schedule != schedule {
+                    panic_with_error!(&e, TokenLockupError::AlreadyUnlocked);
+                }
+            }
+        }
+        Schedule::Periodic(periodic) => {
+            if periodic.period == 0
+                || periodic.periods == 0
+                || periodic.cliff_periods > periodic.periods
+            {
+                panic_with_error!(&e, TokenLockupError::InvalidUnlocks);
+            }
+            let duration = periodic.period.checked_mul(periodic.periods as u64);
+            if duration
+                .and_then(|d| d.checked_add(periodic.start))
+                .is_none()
+            {
+                panic_with_error!(&e, TokenLockupError::OverflowError);
+            }
+
+            if let Some(prev_schedule) = prev_schedule_opt {
+                if prev_schedule.start_time() <= now && prev_schedule != schedule {
+                    panic_with_error!(&e, TokenLockupError::AlreadyUnlocked);
+                }
+            }
+        }
+        Schedule::Amount(unlocks) => {
+            let prev_unlocks_opt = match prev_schedule_opt {
+                Some(Schedule::Amount(prev_unlocks)) => Some(prev_unlocks.clone()),
+                _ => None,
+            };
+            require_valid_amount_unlocks(e, unlocks, &prev_unlocks_opt);
+        }
+    }
+}
+
+/// Get the unlocks of the previous schedule, if it is defined by a vector of unlocks
+fn get_prev_unlocks(prev_schedule_opt: &Option<Schedule>) -> Option<Vec<Unlock>> {
+    match prev_schedule_opt {
+        Some(Schedule::Step(prev_unlocks))
+        | Some(Schedule::Linear(prev_unlocks))
+        | Some(Schedule::Cumulative(prev_unlocks))
+        | Some(Schedule::LedgerStep(prev_unlocks))
+        | Some(Schedule::LedgerLinear(prev_unlocks)) => Some(prev_unlocks.clone()),
+        _ => None,
+    }
+}
+
+/// Validate a vector of unlocks against the previous unlocks, if they exist
+fn require_valid_unlock_vec(
+    e: &Env,
+    unlocks: &Vec<Unlock>,
+    is_linear: bool,
+    prev_unlocks_opt: &Option<Vec<Unlock>>,
+    now: u64,
+) {
+    if unlocks.is_empty() || unlocks.len() > 48 || unlocks.last_unchecked().percent != 10000 {
+        panic_with_error!(&e, TokenLockupError::InvalidUnlocks);
+    }
+
     let mut last_time = 0;
     for (i, unlock) in unlocks.iter().enumerate() {
-        if unlock.percent > 10000 || unlock.percent == 0 {
+        if unlock.percent > 10000 || (unlock.percent == 0 && !(is_linear && i == 0)) {
             panic_with_error!(&e, TokenLockupError::InvalidUnlocks);
         }
 
         if let Some(ref prev_unlocks) = prev_unlocks_opt {
             // validate that any old unlocks remain unchanged
             if let Some(prev_unlock) = prev_unlocks.get(i as u32) {
-                if prev_unlock.time <= e.ledger().timestamp() && prev_unlock != unlock {
+                let is_accruing =
+                    is_linear && i > 0 && prev_unlocks.get_unchecked(i as u32 - 1).time <= now;
+                if (prev_unlock.time <= now || is_accruing) && prev_unlock != unlock {
                     panic_with_error!(&e, TokenLockupError::AlreadyUnlocked);
                 }
             }
@@ -41,18 +237,113 @@ pub fn require_valid_unlocks(e: &Env, unlocks: &Vec<Unlock>) {
     }
 }
 
+/// Validate an amount schedule against the previous unlocks, if they exist
+fn require_valid_amount_unlocks(
+    e: &Env,
+    unlocks: &Vec<AmountUnlock>,
+    prev_unlocks_opt: &Option<Vec<AmountUnlock>>,
+) {
+    if unlocks.is_empty() || unlocks.len() > 48 {
+        panic_with_error!(&e, TokenLockupError::InvalidUnlocks);
+    }
+
+    let now = e.ledger().timestamp();
+    if let Some(ref prev_unlocks) = prev_unlocks_opt {
+        // validate that any old unlocks remain unchanged
+        for (i, prev_unlock) in prev_unlocks.iter().enumerate() {
+            if prev_unlock.time <= now && unlocks.get(i as u32) != Some(prev_unlock) {
+                panic_with_error!(&e, TokenLockupError::AlreadyUnlocked);
+            }
+        }
+    }
+
+    let mut last_time = 0;
+    let mut total: i128 = 0;
+    for unlock in unlocks.iter() {
+        if unlock.amount <= 0 || unlock.time <= last_time {
+            panic_with_error!(&e, TokenLockupError::InvalidUnlocks);
+        }
+        total = match total.checked_add(unlock.amount) {
+            Some(total) => total,
+            None => panic_with_error!(&e, TokenLockupError::OverflowError),
+        };
+        last_time = unlock.time;
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
-    use soroban_sdk::vec;
+    use soroban_sdk::{map, testutils::Address as _, vec};
 
-    use crate::testutils::EnvTestUtils;
+    use crate::{
+        testutils::EnvTestUtils,
+        types::{CliffLinear, Periodic},
+    };
 
     use super::*;
 
+    #[test]
+    fn test_require_valid_shares() {
+        let e = Env::default();
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_shares(&e, &map![&e]);
+            require_valid_shares(
+                &e,
+                &map![
+                    &e,
+                    (Address::generate(&e), 2500),
+                    (Address::generate(&e), 7500)
+                ],
+            );
+            assert!(true);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #112)")]
+    fn test_require_valid_shares_under_total() {
+        let e = Env::default();
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_shares(
+                &e,
+                &map![
+                    &e,
+                    (Address::generate(&e), 2500),
+                    (Address::generate(&e), 7499)
+                ],
+            );
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #112)")]
+    fn test_require_valid_shares_zero_share() {
+        let e = Env::default();
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_shares(
+                &e,
+                &map![
+                    &e,
+                    (Address::generate(&e), 10000),
+                    (Address::generate(&e), 0)
+                ],
+            );
+            assert!(false);
+        });
+    }
+
     #[test]
     fn test_require_valid_unlocks_first_time() {
         let e = Env::default();
-        let unlocks = vec![
+        let unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -66,12 +357,12 @@ mod tests {
                 time: 500,
                 percent: 10000,
             },
-        ];
+        ]);
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            require_valid_unlocks(&e, &unlocks);
+            require_valid_unlocks(&e, &unlocks, &None);
             assert!(true);
         });
     }
@@ -80,12 +371,12 @@ mod tests {
     #[should_panic(expected = "Error(Contract, #100)")]
     fn test_require_valid_unlocks_empty() {
         let e = Env::default();
-        let unlocks = vec![&e];
+        let unlocks = Schedule::Step(vec![&e]);
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            require_valid_unlocks(&e, &unlocks);
+            require_valid_unlocks(&e, &unlocks, &None);
             assert!(false);
         });
     }
@@ -94,12 +385,12 @@ mod tests {
     #[should_panic(expected = "Error(Contract, #100)")]
     fn test_require_valid_unlocks_over_48() {
         let e = Env::default();
-        let unlocks = vec![&e];
+        let unlocks = Schedule::Step(vec![&e]);
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            require_valid_unlocks(&e, &unlocks);
+            require_valid_unlocks(&e, &unlocks, &None);
             assert!(false);
         });
     }
@@ -108,7 +399,7 @@ mod tests {
     #[should_panic(expected = "Error(Contract, #100)")]
     fn test_require_valid_unlocks_does_not_end_with_100_percent() {
         let e = Env::default();
-        let unlocks = vec![
+        let unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -122,12 +413,12 @@ mod tests {
                 time: 500,
                 percent: 10000 - 1,
             },
-        ];
+        ]);
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            require_valid_unlocks(&e, &unlocks);
+            require_valid_unlocks(&e, &unlocks, &None);
             assert!(false);
         });
     }
@@ -136,7 +427,7 @@ mod tests {
     #[should_panic(expected = "Error(Contract, #100)")]
     fn test_require_valid_unlocks_invalid_percent() {
         let e = Env::default();
-        let unlocks = vec![
+        let unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -150,12 +441,12 @@ mod tests {
                 time: 500,
                 percent: 10000 + 1,
             },
-        ];
+        ]);
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            require_valid_unlocks(&e, &unlocks);
+            require_valid_unlocks(&e, &unlocks, &None);
             assert!(false);
         });
     }
@@ -164,7 +455,7 @@ mod tests {
     #[should_panic(expected = "Error(Contract, #100)")]
     fn test_require_valid_unlocks_out_of_order() {
         let e = Env::default();
-        let unlocks = vec![
+        let unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -182,12 +473,12 @@ mod tests {
                 time: 500,
                 percent: 10000,
             },
-        ];
+        ]);
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            require_valid_unlocks(&e, &unlocks);
+            require_valid_unlocks(&e, &unlocks, &None);
             assert!(false);
         });
     }
@@ -196,7 +487,7 @@ mod tests {
     #[should_panic(expected = "Error(Contract, #100)")]
     fn test_require_valid_unlocks_duplicate_unlock_time() {
         let e = Env::default();
-        let unlocks = vec![
+        let unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -214,12 +505,12 @@ mod tests {
                 time: 500,
                 percent: 10000,
             },
-        ];
+        ]);
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            require_valid_unlocks(&e, &unlocks);
+            require_valid_unlocks(&e, &unlocks, &None);
             assert!(false);
         });
     }
@@ -229,7 +520,7 @@ mod tests {
         let e = Env::default();
         e.jump_time_only(300); // t = 300
 
-        let old_unlocks = vec![
+        let old_unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -243,8 +534,8 @@ mod tests {
                 time: 500,
                 percent: 10000,
             },
-        ];
-        let new_unlocks = vec![
+        ]);
+        let new_unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -262,24 +553,144 @@ mod tests {
                 time: 800,
                 percent: 10000,
             },
-        ];
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(true);
+        });
+    }
+
+    #[test]
+    fn test_require_valid_unlocks_periodic() {
+        let e = Env::default();
+        let unlocks = Schedule::Periodic(Periodic {
+            start: 100,
+            period: 10,
+            periods: 1000,
+            cliff_periods: 100,
+        });
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            storage::set_unlocks(&e, &old_unlocks);
-            require_valid_unlocks(&e, &new_unlocks);
+            require_valid_unlocks(&e, &unlocks, &None);
             assert!(true);
         });
     }
 
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_valid_unlocks_periodic_cliff_after_end() {
+        let e = Env::default();
+        let unlocks = Schedule::Periodic(Periodic {
+            start: 100,
+            period: 10,
+            periods: 100,
+            cliff_periods: 101,
+        });
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #12)")]
+    fn test_require_valid_unlocks_periodic_overflow() {
+        let e = Env::default();
+        let unlocks = Schedule::Periodic(Periodic {
+            start: 100,
+            period: u64::MAX / 10,
+            periods: 100,
+            cliff_periods: 0,
+        });
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #102)")]
+    fn test_require_valid_unlocks_ledger_replaces_changes_old() {
+        let e = Env::default();
+        e.jump(300); // sequence = 300
+
+        let old_unlocks = Schedule::LedgerStep(vec![
+            &e,
+            Unlock {
+                time: 200,
+                percent: 5000,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+        let new_unlocks = Schedule::LedgerStep(vec![
+            &e,
+            Unlock {
+                time: 250,
+                percent: 5000,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_valid_unlocks_ledger_replaces_time() {
+        let e = Env::default();
+
+        let old_unlocks = Schedule::Step(vec![
+            &e,
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+        let new_unlocks = Schedule::LedgerStep(vec![
+            &e,
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(false);
+        });
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #102)")]
     fn test_require_valid_unlocks_replaces_changes_old() {
         let e = Env::default();
         e.jump_time_only(300); // t = 300
 
-        let old_unlocks = vec![
+        let old_unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -293,8 +704,8 @@ mod tests {
                 time: 500,
                 percent: 10000,
             },
-        ];
-        let new_unlocks = vec![
+        ]);
+        let new_unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -308,13 +719,12 @@ mod tests {
                 time: 800,
                 percent: 10000,
             },
-        ];
+        ]);
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            storage::set_unlocks(&e, &old_unlocks);
-            require_valid_unlocks(&e, &new_unlocks);
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
             assert!(false);
         });
     }
@@ -325,7 +735,7 @@ mod tests {
         let e = Env::default();
         e.jump_time_only(500); // t = 500
 
-        let old_unlocks = vec![
+        let old_unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -339,8 +749,8 @@ mod tests {
                 time: 500,
                 percent: 10000,
             },
-        ];
-        let new_unlocks = vec![
+        ]);
+        let new_unlocks = Schedule::Step(vec![
             &e,
             Unlock {
                 time: 100,
@@ -358,13 +768,457 @@ mod tests {
                 time: 800,
                 percent: 10000,
             },
-        ];
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(false);
+        });
+    }
+
+    #[test]
+    fn test_require_valid_unlocks_linear_starts_at_zero() {
+        let e = Env::default();
+        let unlocks = Schedule::Linear(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 0,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(true);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_valid_unlocks_step_zero_percent() {
+        let e = Env::default();
+        let unlocks = Schedule::Step(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 0,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_valid_unlocks_linear_zero_percent_after_start() {
+        let e = Env::default();
+        let unlocks = Schedule::Linear(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 0,
+            },
+            Unlock {
+                time: 200,
+                percent: 0,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #102)")]
+    fn test_require_valid_unlocks_linear_replaces_accruing() {
+        let e = Env::default();
+        e.jump_time_only(300); // t = 300
+
+        let old_unlocks = Schedule::Linear(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 0,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+        let new_unlocks = Schedule::Linear(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 0,
+            },
+            Unlock {
+                time: 800,
+                percent: 10000,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(false);
+        });
+    }
+
+    #[test]
+    fn test_require_valid_unlocks_changes_type_before_start() {
+        let e = Env::default();
+        e.jump_time_only(50); // t = 50
+
+        let old_unlocks = Schedule::Step(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 5000,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+        let new_unlocks = Schedule::Linear(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 0,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(true);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #102)")]
+    fn test_require_valid_unlocks_changes_type_after_start() {
+        let e = Env::default();
+        e.jump_time_only(100); // t = 100
+
+        let old_unlocks = Schedule::Step(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 5000,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+        let new_unlocks = Schedule::Linear(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 5000,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(false);
+        });
+    }
+
+    #[test]
+    fn test_require_valid_unlocks_cliff_linear() {
+        let e = Env::default();
+        let unlocks = Schedule::CliffLinear(CliffLinear {
+            start: 100,
+            cliff: 100,
+            end: 500,
+            cliff_percent: 0,
+        });
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(true);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_valid_unlocks_cliff_linear_cliff_before_start() {
+        let e = Env::default();
+        let unlocks = Schedule::CliffLinear(CliffLinear {
+            start: 200,
+            cliff: 100,
+            end: 500,
+            cliff_percent: 2500,
+        });
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_valid_unlocks_cliff_linear_cliff_at_end() {
+        let e = Env::default();
+        let unlocks = Schedule::CliffLinear(CliffLinear {
+            start: 100,
+            cliff: 500,
+            end: 500,
+            cliff_percent: 2500,
+        });
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_valid_unlocks_cliff_linear_invalid_percent() {
+        let e = Env::default();
+        let unlocks = Schedule::CliffLinear(CliffLinear {
+            start: 100,
+            cliff: 200,
+            end: 500,
+            cliff_percent: 10001,
+        });
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(false);
+        });
+    }
+
+    #[test]
+    fn test_require_valid_unlocks_cliff_linear_replaces_before_cliff() {
+        let e = Env::default();
+        e.jump_time_only(150); // t = 150
+
+        let old_unlocks = Schedule::CliffLinear(CliffLinear {
+            start: 100,
+            cliff: 200,
+            end: 500,
+            cliff_percent: 2500,
+        });
+        let new_unlocks = Schedule::CliffLinear(CliffLinear {
+            start: 100,
+            cliff: 300,
+            end: 600,
+            cliff_percent: 5000,
+        });
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(true);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #102)")]
+    fn test_require_valid_unlocks_cliff_linear_replaces_after_cliff() {
+        let e = Env::default();
+        e.jump_time_only(200); // t = 200
+
+        let old_unlocks = Schedule::CliffLinear(CliffLinear {
+            start: 100,
+            cliff: 200,
+            end: 500,
+            cliff_percent: 2500,
+        });
+        let new_unlocks = Schedule::CliffLinear(CliffLinear {
+            start: 100,
+            cliff: 200,
+            end: 600,
+            cliff_percent: 2500,
+        });
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(false);
+        });
+    }
+
+    #[test]
+    fn test_require_valid_unlocks_amount() {
+        let e = Env::default();
+        let unlocks = Schedule::Amount(vec![
+            &e,
+            AmountUnlock {
+                time: 100,
+                amount: 100,
+            },
+            AmountUnlock {
+                time: 200,
+                amount: 200,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(true);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_lockup_schedule_amount() {
+        let e = Env::default();
+        let unlocks = Schedule::Amount(vec![
+            &e,
+            AmountUnlock {
+                time: 100,
+                amount: 100,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_lockup_schedule(&e, &unlocks);
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_valid_unlocks_amount_zero_amount() {
+        let e = Env::default();
+        let unlocks = Schedule::Amount(vec![
+            &e,
+            AmountUnlock {
+                time: 100,
+                amount: 100,
+            },
+            AmountUnlock {
+                time: 200,
+                amount: 0,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &unlocks, &None);
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #102)")]
+    fn test_require_valid_unlocks_amount_replaces_changes_old() {
+        let e = Env::default();
+        e.jump_time_only(150); // t = 150
+
+        let old_unlocks = Schedule::Amount(vec![
+            &e,
+            AmountUnlock {
+                time: 100,
+                amount: 100,
+            },
+            AmountUnlock {
+                time: 200,
+                amount: 200,
+            },
+        ]);
+        let new_unlocks = Schedule::Amount(vec![
+            &e,
+            AmountUnlock {
+                time: 100,
+                amount: 150,
+            },
+            AmountUnlock {
+                time: 200,
+                amount: 150,
+            },
+        ]);
+
+        let lockup = e.register_contract(None, crate::contract::TokenLockup {});
+
+        e.as_contract(&lockup, || {
+            require_valid_unlocks(&e, &new_unlocks, &Some(old_unlocks));
+            assert!(false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #100)")]
+    fn test_require_valid_unlocks_cumulative_decreasing_percent() {
+        let e = Env::default();
+        let unlocks = Schedule::Cumulative(vec![
+            &e,
+            Unlock {
+                time: 100,
+                percent: 5000,
+            },
+            Unlock {
+                time: 200,
+                percent: 2500,
+            },
+            Unlock {
+                time: 500,
+                percent: 10000,
+            },
+        ]);
 
         let lockup = e.register_contract(None, crate::contract::TokenLockup {});
 
         e.as_contract(&lockup, || {
-            storage::set_unlocks(&e, &old_unlocks);
-            require_valid_unlocks(&e, &new_unlocks);
+            require_valid_unlocks(&e, &unlocks, &None);
             assert!(false);
         });
     }